@@ -0,0 +1,189 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use miette::{IntoDiagnostic, WrapErr};
+use serde::{Deserialize, Serialize};
+
+pub struct BenchConfig {
+    /// Upper bound on the number of samples per stage
+    pub iterations: usize,
+    /// Stop sampling a stage early once this much time was spent on it
+    pub budget: Duration,
+}
+
+pub struct Samples {
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+/// Runs `f` until either the iteration count or the time budget is exhausted, always at least once
+pub fn sample<T>(config: &BenchConfig, mut f: impl FnMut() -> T) -> Vec<Duration> {
+    let mut samples = Vec::new();
+    let budget_start = Instant::now();
+
+    while samples.is_empty() || (samples.len() < config.iterations && budget_start.elapsed() < config.budget) {
+        let start = Instant::now();
+        black_box(f());
+        samples.push(start.elapsed());
+    }
+
+    samples
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub p95_ns: u64,
+}
+
+impl Stats {
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        sorted.sort_unstable();
+
+        // Nearest-rank percentile
+        let percentile = |p: usize| sorted[((sorted.len() * p).div_ceil(100)).max(1) - 1];
+
+        Self {
+            samples: sorted.len(),
+            min_ns: sorted[0],
+            median_ns: percentile(50),
+            p95_ns: percentile(95),
+        }
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:>10} {:>10} {:>10}",
+            format!("{:.1?}", Duration::from_nanos(self.min_ns)),
+            format!("{:.1?}", Duration::from_nanos(self.median_ns)),
+            format!("{:.1?}", Duration::from_nanos(self.p95_ns)),
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Copy, Clone, Debug, PartialEq)]
+pub struct Entry {
+    pub parse: Stats,
+    pub solve: Stats,
+}
+
+/// Bench results keyed by `dayNN/partN`
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct Baseline(pub BTreeMap<String, Entry>);
+
+impl Baseline {
+    pub fn key(day: u32, part: u32) -> String {
+        format!("day{:02}/part{}", day, part)
+    }
+
+    pub fn load(path: &Path) -> miette::Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read_to_string(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't read baseline {}", path.display()))?;
+
+        serde_json::from_str(&contents)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Invalid baseline {}", path.display()))
+            .map(Some)
+    }
+
+    pub fn save(&self, path: &Path) -> miette::Result<()> {
+        let contents = serde_json::to_string_pretty(self).into_diagnostic()?;
+
+        std::fs::write(path, contents + "\n")
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't write baseline {}", path.display()))
+    }
+}
+
+/// Compares medians, returns the relative change of the current run if it exceeds the threshold
+pub fn regression(baseline: &Stats, current: &Stats, threshold: f64) -> Option<f64> {
+    let change = current.median_ns as f64 / baseline.median_ns.max(1) as f64 - 1.0;
+    (change > threshold).then_some(change)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::new(&millis(&[5, 1, 4, 2, 3]));
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min_ns, 1_000_000);
+        assert_eq!(stats.median_ns, 3_000_000);
+        assert_eq!(stats.p95_ns, 5_000_000);
+
+        let stats = Stats::new(&millis(&(1..=100).collect::<Vec<_>>()));
+        assert_eq!(stats.median_ns, 50_000_000);
+        assert_eq!(stats.p95_ns, 95_000_000);
+    }
+
+    #[test]
+    fn test_stats_single_sample() {
+        let stats = Stats::new(&millis(&[7]));
+        assert_eq!(stats.min_ns, 7_000_000);
+        assert_eq!(stats.median_ns, 7_000_000);
+        assert_eq!(stats.p95_ns, 7_000_000);
+    }
+
+    #[test]
+    fn test_regression() {
+        let baseline = Stats::new(&millis(&[10]));
+
+        assert_eq!(regression(&baseline, &Stats::new(&millis(&[11])), 0.2), None);
+        assert_eq!(regression(&baseline, &Stats::new(&millis(&[5])), 0.2), None);
+
+        let change = regression(&baseline, &Stats::new(&millis(&[15])), 0.2).unwrap();
+        assert!((change - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_sample_respects_iterations() {
+        let config = BenchConfig {
+            iterations: 7,
+            budget: Duration::from_secs(60),
+        };
+        assert_eq!(sample(&config, || ()).len(), 7);
+    }
+
+    #[test]
+    fn test_sample_runs_at_least_once() {
+        let config = BenchConfig {
+            iterations: 100,
+            budget: Duration::ZERO,
+        };
+        assert_eq!(sample(&config, || ()).len(), 1);
+    }
+
+    #[test]
+    fn test_baseline_roundtrip() {
+        let stats = Stats::new(&millis(&[1, 2, 3]));
+        let mut baseline = Baseline::default();
+        baseline.0.insert(Baseline::key(5, 2), Entry { parse: stats, solve: stats });
+
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert!(json.contains("\"day05/part2\""));
+
+        let parsed: Baseline = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.0, baseline.0);
+    }
+}
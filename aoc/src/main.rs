@@ -0,0 +1,339 @@
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use clap::{Args, Parser, Subcommand};
+use miette::miette;
+use aoc2023::input::InputSource;
+use crate::bench::{BenchConfig, Baseline, Entry, Stats};
+use crate::registry::Day;
+use crate::verify::{Answers, Status};
+
+mod bench;
+mod registry;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Args)]
+struct Selection {
+    #[arg(long, required_unless_present = "all")]
+    day: Option<u32>,
+
+    /// Only run this part, both parts are run if omitted
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=2))]
+    part: Option<u32>,
+
+    /// Read the puzzle input from this file, or from stdin when `-`
+    #[arg(long, conflicts_with = "all")]
+    input: Option<PathBuf>,
+
+    /// Directory holding the `dayNN.txt` inputs, defaults to $AOC_INPUTS or the workspace `inputs` directory
+    #[arg(long)]
+    inputs_dir: Option<PathBuf>,
+
+    #[arg(long, conflicts_with = "day")]
+    all: bool,
+}
+
+impl Selection {
+    fn parts(&self) -> Vec<u32> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+
+    fn days(&self) -> miette::Result<Vec<Day>> {
+        match self.day {
+            Some(day) if !self.all => registry::find(day)
+                .map(|day| vec![day])
+                .ok_or_else(|| miette!("No solver registered for day {}", day)),
+            _ => Ok(registry::days()),
+        }
+    }
+
+    fn source(&self) -> InputSource {
+        InputSource::from_arg(self.input.clone(), self.inputs_dir.clone())
+    }
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solver for one day, or for every day with --all
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+    },
+    /// Time parsing and solving over many runs and compare against a saved baseline
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+
+        /// Maximum number of samples per stage
+        #[arg(long, default_value_t = 100)]
+        iterations: usize,
+
+        /// Stop sampling a stage after this many seconds, even if fewer iterations were done
+        #[arg(long, default_value_t = 5.0)]
+        budget: f64,
+
+        /// JSON file holding the baseline results
+        #[arg(long, default_value = "bench.json")]
+        baseline: PathBuf,
+
+        /// Write the results to the baseline file, replacing the entries for the benched days
+        #[arg(long)]
+        save: bool,
+
+        /// Relative slowdown of the median, in percent, that counts as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Check every solver against the recorded answers for its real input
+    Verify {
+        /// Only verify this day
+        #[arg(long)]
+        day: Option<u32>,
+
+        /// TOML file with the expected answers
+        #[arg(long, default_value = "answers.toml")]
+        answers: PathBuf,
+
+        /// Directory holding the `dayNN.txt` inputs, defaults to $AOC_INPUTS or the workspace `inputs` directory
+        #[arg(long)]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Write the day 19 workflow graph in Graphviz DOT
+    Graph {
+        /// Simplify the workflows first, inlining and collapsing what can be
+        #[arg(long)]
+        simplify: bool,
+
+        /// File to write the graph to, printed if omitted
+        #[arg(long)]
+        output: Option<PathBuf>,
+
+        /// Read the puzzle input from this file, or from stdin when `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Directory holding the `dayNN.txt` inputs, defaults to $AOC_INPUTS or the workspace `inputs` directory
+        #[arg(long)]
+        inputs_dir: Option<PathBuf>,
+    },
+    /// Show step by step how the day 19 workflows sort parts
+    Explain {
+        /// Part to explain, like `{x=787,m=2655,a=1222,s=2876}`, every part in the input if omitted
+        #[arg(long)]
+        part: Option<String>,
+
+        /// Read the puzzle input from this file, or from stdin when `-`
+        #[arg(long)]
+        input: Option<PathBuf>,
+
+        /// Directory holding the `dayNN.txt` inputs, defaults to $AOC_INPUTS or the workspace `inputs` directory
+        #[arg(long)]
+        inputs_dir: Option<PathBuf>,
+    },
+}
+
+fn run(selection: &Selection) -> miette::Result<()> {
+    let source = selection.source();
+
+    for day in selection.days()? {
+        let input = source.load(day.day)?;
+
+        for part in selection.parts() {
+            let run = day.run(part, &input)?;
+            println!(
+                "Day {} part {}: {} (parse {:?}, solve {:?})",
+                day.day, part, run.answer, run.parse_time, run.solve_time
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether any regressions were found
+fn bench(selection: &Selection, config: &BenchConfig, baseline_path: &Path, save: bool, threshold: f64) -> miette::Result<bool> {
+    let source = selection.source();
+    let baseline = Baseline::load(baseline_path)?;
+    let mut results = baseline.clone().unwrap_or_default();
+    let mut regressed = false;
+
+    println!("{:<12} {:<6} {:>7} {:>10} {:>10} {:>10}", "", "stage", "samples", "min", "median", "p95");
+
+    for day in selection.days()? {
+        let input = source.load(day.day)?;
+
+        for part in selection.parts() {
+            let samples = day.bench(part, &input, config)?;
+            let key = Baseline::key(day.day, part);
+            let entry = Entry {
+                parse: Stats::new(&samples.parse),
+                solve: Stats::new(&samples.solve),
+            };
+            let previous = baseline.as_ref().and_then(|b| b.0.get(&key));
+
+            for (stage, stats, old) in [
+                ("parse", entry.parse, previous.map(|e| e.parse)),
+                ("solve", entry.solve, previous.map(|e| e.solve)),
+            ] {
+                let flag = match old.and_then(|old| bench::regression(&old, &stats, threshold / 100.0)) {
+                    Some(change) => {
+                        regressed = true;
+                        format!("  REGRESSION +{:.1}%", change * 100.0)
+                    }
+                    None => String::new(),
+                };
+                println!("{:<12} {:<6} {:>7} {}{}", key, stage, stats.samples, stats, flag);
+            }
+
+            results.0.insert(key, entry);
+        }
+    }
+
+    if save {
+        results.save(baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+
+    Ok(regressed)
+}
+
+/// Returns whether every recorded answer matched
+fn verify(day: Option<u32>, answers_path: &Path, inputs_dir: Option<PathBuf>) -> miette::Result<bool> {
+    let answers = Answers::load(answers_path)?;
+    let source = InputSource::from_arg(None, inputs_dir);
+    let days = match day {
+        Some(day) => vec![registry::find(day).ok_or_else(|| miette!("No solver registered for day {}", day))?],
+        None => registry::days(),
+    };
+
+    let mut counts = [0; 4];
+
+    println!("{:<5} {:<5} {:>12}  status", "day", "part", "time");
+
+    for day in days {
+        let input = source.load(day.day);
+
+        for part in [1, 2] {
+            let (status, time) = match (answers.get(day.day, part), &input) {
+                (None, _) => (Status::Missing, None),
+                (Some(_), Err(err)) => (Status::Error(err.to_string()), None),
+                // A panicking solver is reported like any other error, and the other days still run
+                (Some(expected), Ok(input)) => match panic::catch_unwind(|| day.run(part, input)) {
+                    Ok(Ok(run)) => (Status::check(expected, &run.answer), Some(run.parse_time + run.solve_time)),
+                    Ok(Err(err)) => (Status::Error(err.to_string()), None),
+                    Err(payload) => (Status::Error(format!("panicked: {}", verify::panic_message(&*payload))), None),
+                },
+            };
+
+            counts[match status {
+                Status::Pass => 0,
+                Status::Fail { .. } => 1,
+                Status::Missing => 2,
+                Status::Error(_) => 3,
+            }] += 1;
+
+            let time = time.map(|t| format!("{:.1?}", t)).unwrap_or_default();
+            println!("{:<5} {:<5} {:>12}  {}", day.day, part, time, status);
+        }
+    }
+
+    let [pass, fail, missing, error] = counts;
+    println!("\n{} passed, {} failed, {} missing, {} errors", pass, fail, missing, error);
+
+    Ok(fail == 0 && error == 0)
+}
+
+fn explain(part: Option<&str>, source: &InputSource) -> miette::Result<()> {
+    let input = source.load(19)?;
+    let (workflows, parts) = day19::parse(&input).map_err(|err| err.with_source(&input))?;
+
+    let given;
+    let parts = match part {
+        Some(part) => {
+            given = day19::Part::new(part).map_err(|err| err.with_source(part))?;
+            std::slice::from_ref(&given)
+        }
+        None => &parts[..],
+    };
+
+    for part in parts {
+        let trace = day19::trace(&workflows, part).map_err(|err| miette!("{}: {}", part, err))?;
+        let verdict = match trace.verdict() {
+            day19::Target::Accept => "accepted",
+            _ => "rejected",
+        };
+
+        println!("{} is {}", part, verdict);
+        for step in &trace.steps {
+            println!("  {}", step);
+        }
+    }
+
+    Ok(())
+}
+
+fn graph(simplify: bool, output: Option<&Path>, source: &InputSource) -> miette::Result<()> {
+    let input = source.load(19)?;
+    let (workflows, _parts) = day19::parse(&input).map_err(|err| err.with_source(&input))?;
+
+    let workflows = match simplify {
+        true => {
+            let simplified = day19::simplify::simplify(&workflows, &day19::Domain::xmas());
+            eprintln!("Simplified {} workflows down to {}", workflows.len(), simplified.len());
+            simplified
+        }
+        false => workflows,
+    };
+    let dot = day19::dot::to_dot(&workflows);
+
+    match output {
+        Some(path) => std::fs::write(path, dot)
+            .map_err(|err| miette!("Couldn't write {}: {}", path.display(), err)),
+        None => {
+            print!("{}", dot);
+            Ok(())
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let result = match cli.command {
+        Command::Run { selection } => run(&selection).map(|_| true),
+        Command::Bench { selection, iterations, budget, baseline, save, threshold } => {
+            let config = BenchConfig {
+                iterations,
+                budget: Duration::from_secs_f64(budget),
+            };
+            bench(&selection, &config, &baseline, save, threshold).map(|regressed| !regressed)
+        }
+        Command::Verify { day, answers, inputs_dir } => verify(day, &answers, inputs_dir),
+        Command::Graph { simplify, output, input, inputs_dir } => {
+            graph(simplify, output.as_deref(), &InputSource::from_arg(input, inputs_dir)).map(|_| true)
+        }
+        Command::Explain { part, input, inputs_dir } => {
+            explain(part.as_deref(), &InputSource::from_arg(input, inputs_dir)).map(|_| true)
+        }
+    };
+
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(report) => {
+            eprintln!("{:?}", report);
+            ExitCode::FAILURE
+        }
+    }
+}
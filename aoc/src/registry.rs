@@ -0,0 +1,111 @@
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+use aoc2023::Solution;
+use crate::bench::{sample, BenchConfig, Samples};
+
+pub type RunFn = fn(&str, u32) -> miette::Result<Run>;
+pub type BenchFn = fn(&str, u32, &BenchConfig) -> miette::Result<Samples>;
+
+pub struct Day {
+    pub day: u32,
+    pub run: RunFn,
+    pub bench: BenchFn,
+}
+
+impl Day {
+    pub fn run(&self, part: u32, input: &str) -> miette::Result<Run> {
+        (self.run)(input, part)
+    }
+
+    pub fn bench(&self, part: u32, input: &str, config: &BenchConfig) -> miette::Result<Samples> {
+        (self.bench)(input, part, config)
+    }
+}
+
+pub struct Run {
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+fn timed<T: Display>(f: impl FnOnce() -> miette::Result<T>) -> miette::Result<(String, Duration)> {
+    let start = Instant::now();
+    let answer = f()?;
+    let elapsed = start.elapsed();
+    Ok((answer.to_string(), elapsed))
+}
+
+pub fn run<S: Solution>(input: &str, part: u32) -> miette::Result<Run> {
+    let start = Instant::now();
+    let parsed = S::parse(input)?;
+    let parse_time = start.elapsed();
+
+    let (answer, solve_time) = match part {
+        1 => timed(|| S::part1(&parsed)),
+        _ => timed(|| S::part2(&parsed)),
+    }?;
+
+    Ok(Run {
+        answer,
+        parse_time,
+        solve_time,
+    })
+}
+
+pub fn bench<S: Solution>(input: &str, part: u32, config: &BenchConfig) -> miette::Result<Samples> {
+    // Parse and solve once up front so errors surface instead of being timed
+    let parsed = S::parse(input)?;
+    match part {
+        1 => S::part1(&parsed).map(drop)?,
+        _ => S::part2(&parsed).map(drop)?,
+    }
+
+    let parse = sample(config, || S::parse(input));
+    let solve = match part {
+        1 => sample(config, || S::part1(&parsed)),
+        _ => sample(config, || S::part2(&parsed)),
+    };
+
+    Ok(Samples {
+        parse,
+        solve,
+    })
+}
+
+macro_rules! day {
+    ($day:literal, $krate:ident :: $solution:ident) => {
+        Day {
+            day: $day,
+            run: run::<$krate::$solution>,
+            bench: bench::<$krate::$solution>,
+        }
+    };
+}
+
+pub fn days() -> Vec<Day> {
+    vec![
+        day!(1, day1::Day1),
+        day!(2, day2::Day2),
+        day!(3, day3::Day3),
+        day!(4, day4::Day4),
+        day!(5, day5::Day5),
+        day!(6, day6::Day6),
+        day!(7, day7::Day7),
+        day!(8, day8::Day8),
+        day!(9, day9::Day9),
+        day!(10, day10::Day10),
+        day!(11, day11::Day11),
+        day!(12, day12::Day12),
+        day!(13, day13::Day13),
+        day!(14, day14::Day14),
+        day!(15, day15::Day15),
+        day!(16, day16::Day16),
+        day!(17, day17::Day17),
+        day!(18, day18::Day18),
+        day!(19, day19::Day19),
+    ]
+}
+
+pub fn find(day: u32) -> Option<Day> {
+    days().into_iter().find(|d| d.day == day)
+}
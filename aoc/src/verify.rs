@@ -0,0 +1,128 @@
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+use miette::{IntoDiagnostic, WrapErr};
+use serde::Deserialize;
+
+#[derive(Deserialize, Default, Debug, PartialEq)]
+pub struct DayAnswers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// Expected answers, keyed by `dayNN` tables holding `part1`/`part2` strings
+#[derive(Deserialize, Default, Debug)]
+pub struct Answers(pub BTreeMap<String, DayAnswers>);
+
+impl Answers {
+    pub fn load(path: &Path) -> miette::Result<Self> {
+        let contents = std::fs::read_to_string(path)
+            .into_diagnostic()
+            .wrap_err_with(|| format!("Couldn't read answers file {}", path.display()))?;
+
+        Self::parse(&contents)
+            .wrap_err_with(|| format!("Invalid answers file {}", path.display()))
+    }
+
+    pub fn parse(contents: &str) -> miette::Result<Self> {
+        toml::from_str(contents).into_diagnostic()
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        let answers = self.0.get(&format!("day{:02}", day))?;
+        match part {
+            1 => answers.part1.as_deref(),
+            _ => answers.part2.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail { expected: String, actual: String },
+    /// No expected answer was recorded, the solver isn't run
+    Missing,
+    /// The input couldn't be loaded or parsed, or the solver failed or panicked
+    Error(String),
+}
+
+impl Status {
+    pub fn check(expected: &str, actual: &str) -> Self {
+        if expected.trim() == actual {
+            Status::Pass
+        } else {
+            Status::Fail {
+                expected: expected.trim().to_string(),
+                actual: actual.to_string(),
+            }
+        }
+    }
+}
+
+/// The message a panic was raised with, from the payload [`std::panic::catch_unwind`] returns
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message.to_string(),
+        None => payload.downcast_ref::<String>().cloned().unwrap_or_else(|| "unknown panic".to_string()),
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail { expected, actual } => write!(f, "FAIL (expected {}, got {})", expected, actual),
+            Status::Missing => write!(f, "missing"),
+            Status::Error(message) => write!(f, "ERROR ({})", message),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = r#"
+[day01]
+part1 = "55607"
+part2 = "55291"
+
+[day05]
+part1 = "324724204"
+"#;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(1, 1), Some("55607"));
+        assert_eq!(answers.get(1, 2), Some("55291"));
+        assert_eq!(answers.get(5, 1), Some("324724204"));
+        assert_eq!(answers.get(5, 2), None);
+        assert_eq!(answers.get(6, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Answers::parse("[day01]\npart1 = ").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        assert_eq!(Status::check("42", "42"), Status::Pass);
+        assert_eq!(Status::check(" 42\n", "42"), Status::Pass);
+        assert_eq!(Status::check("42", "41"), Status::Fail {
+            expected: "42".to_string(),
+            actual: "41".to_string(),
+        });
+    }
+
+    #[test]
+    fn test_panic_message() {
+        let payload = std::panic::catch_unwind(|| panic!("Workflow {:?} isn't defined", "in")).unwrap_err();
+        assert_eq!(panic_message(&*payload), "Workflow \"in\" isn't defined");
+        let payload = std::panic::catch_unwind(|| panic!("static")).unwrap_err();
+        assert_eq!(panic_message(&*payload), "static");
+    }
+}
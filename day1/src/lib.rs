@@ -0,0 +1,24 @@
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = Vec<&'a str>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(lines: &Self::Parsed<'_>) -> miette::Result<i32> {
+        part1::solve(lines)
+    }
+
+    fn part2(lines: &Self::Parsed<'_>) -> miette::Result<i32> {
+        part2::solve(lines)
+    }
+}
@@ -0,0 +1,56 @@
+use miette::miette;
+
+pub fn solve(lines: &[&str]) -> miette::Result<i32> {
+    lines.iter()
+        .map(|line| {
+            let mut first = None;
+            let mut last = None;
+            for char in line.chars() {
+                if char.is_ascii_digit() {
+                    if first.is_none() {
+                        first = Some(char);
+                    }
+                    last = Some(char);
+                }
+            }
+            let (first, last) = first.zip(last).ok_or_else(|| miette!("No digit in line {:?}", line))?;
+            let s: String = [first, last].iter().collect();
+            Ok(s.parse::<i32>().unwrap())
+        }).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use crate::Day1;
+
+    #[test]
+    fn it_works() {
+        let input = "1abc2
+pqr3stu8vwx
+a1b2c3d4e5f
+treb7uchet";
+
+        let result = Day1::solve_part1(input).unwrap();
+        assert_eq!(result, 142);
+    }
+
+    #[test]
+    fn test_no_digit() {
+        let err = Day1::solve_part1("1abc2\ntwo").unwrap_err();
+        assert_eq!(err.to_string(), "No digit in line \"two\"");
+    }
+
+    #[test]
+    #[allow(clippy::match_like_matches_macro)]
+    fn do_char_ranges_do_what_i_think() {
+        for n in 0 ..= 9 {
+            let c = char::from_digit(n, 10).unwrap();
+            let is_in_range = match c {
+                '0'..='9' => true,
+                _ => false
+            };
+            assert!(is_in_range)
+        };
+    }
+}
\ No newline at end of file
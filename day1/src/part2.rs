@@ -1,7 +1,9 @@
 
-fn solve(input: &str) -> i32 {
-    
-    let mapping = vec![
+use miette::miette;
+
+pub fn solve(lines: &[&str]) -> miette::Result<i32> {
+
+    let mapping = [
         ("1", 1),
         ("2", 2),
         ("3", 3),
@@ -22,17 +24,17 @@ fn solve(input: &str) -> i32 {
         ("nine", 9),
     ];
     
-    return input.lines()
+    lines.iter()
         .map(|line| {
             let mut first = None;
             let mut last = None;
 
-            for i in 0..line.len() {
+            for (i, _) in line.char_indices() {
                 let line_rest = &line[i..];
 
                 for (from, to) in &mapping {
                     if line_rest.starts_with(from) {
-                        if first == None {
+                        if first.is_none() {
                             first = Some(to);
                         }
                         last = Some(to);
@@ -41,19 +43,16 @@ fn solve(input: &str) -> i32 {
                 }
             }
 
-            return first.unwrap() * 10 + last.unwrap();
-        }).sum();
-}
-
-fn main() {
-    let input = include_str!("../input1.txt");
-    let result = solve(input);
-    println!("Result: {}", result);
+            let (first, last) = first.zip(last).ok_or_else(|| miette!("No digit or spelled out digit in line {:?}", line))?;
+            Ok(first * 10 + last)
+        }).sum()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use crate::Day1;
 
     #[test]
     fn it_works() {
@@ -65,7 +64,7 @@ xtwone3four
 zoneight234
 7pqrstsixteen";
 
-        let result = solve(input);
+        let result = Day1::solve_part2(input).unwrap();
         assert_eq!(result, 281);
     }
 
@@ -81,18 +80,28 @@ zrvznqpcjhtsghfb7
 five8threeonedl
 8threefiveknqhmm4five2";
 
-        let result = solve(input);
+        let result = Day1::solve_part2(input).unwrap();
         assert_eq!(result, 555);
     }
 
     #[test]
     fn replacer() {
         let input = "eightwo";
-        assert_eq!(solve(input), 82);
+        assert_eq!(Day1::solve_part2(input).unwrap(), 82);
     }
 
+    #[test]
+    fn test_no_digit() {
+        assert!(Day1::solve_part2("x").is_err());
+        assert_eq!(Day1::solve_part2("é1étwoé").unwrap(), 12);
+    }
 
-
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::calibration(seed, 100);
+            assert_eq!(Day1::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+            assert_eq!(Day1::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
 }
-
-
@@ -0,0 +1,180 @@
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use aoc2023::error::ParseError;
+use glam::IVec2;
+use miette::miette;
+use aoc2023::direction::Direction;
+use aoc2023::map2d::{Edges, Map2D};
+use aoc2023::search;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = World;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        World::new(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        part1::solve(world)
+    }
+
+    fn part2(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        part2::solve(world)
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    NS,
+    EW,
+    NE,
+    NW,
+    SW,
+    SE,
+    Ground,
+    Start,
+}
+
+impl Tile {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '|' => Some(Self::NS),
+            '-' => Some(Self::EW),
+            'L' => Some(Self::NE),
+            'J' => Some(Self::NW),
+            '7' => Some(Self::SW),
+            'F' => Some(Self::SE),
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            _ => None
+        }
+    }
+
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::NS => '|',
+            Self::EW => '-',
+            Self::NE => 'L',
+            Self::NW => 'J',
+            Self::SW => '7',
+            Self::SE => 'F',
+            Self::Ground => '.',
+            Self::Start => 'S',
+        }
+    }
+
+    /// The directions the pipe leads to, the start tile could lead anywhere
+    pub fn connections(&self) -> &'static [Direction] {
+        match self {
+            Tile::NS => &[Direction::North, Direction::South],
+            Tile::EW => &[Direction::West, Direction::East],
+            Tile::NE => &[Direction::North, Direction::East],
+            Tile::NW => &[Direction::North, Direction::West],
+            Tile::SW => &[Direction::West, Direction::South],
+            Tile::SE => &[Direction::South, Direction::East],
+            Tile::Ground => &[],
+            Tile::Start => &[Direction::North, Direction::South, Direction::East, Direction::West],
+        }
+    }
+
+    pub fn next_positions(&self, cur_x: usize, cur_y: usize) -> Vec<(usize, usize)> {
+        let pos = IVec2::new(cur_x as i32, cur_y as i32);
+
+        self.connections().iter()
+            .map(|direction| pos + direction.as_delta())
+            .filter(|next| next.x >= 0 && next.y >= 0)
+            .map(|next| (next.x as usize, next.y as usize))
+            .collect()
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct World {
+    pub tiles: Map2D<Tile>,
+    pub start_pos: (usize, usize),
+}
+
+impl World {
+    pub fn new(input: &str) -> Result<Self, ParseError> {
+        let tiles = Map2D::parse(input, Tile::new)?;
+
+        let start_pos = *tiles.find_all(&Tile::Start).first()
+            .ok_or_else(|| ParseError::expected_at_end("a start tile 'S'", input))?;
+
+        Ok(Self {
+            tiles,
+            start_pos,
+        })
+    }
+
+    /// The pipe under the start tile, joining the two neighbours that connect back to it
+    pub fn start_tile(&self) -> miette::Result<Tile> {
+        let connected_to_start = find_start_positions(self);
+        let (start_x, start_y) = self.start_pos;
+
+        // n s w e
+        let start_directions = (
+            if start_y > 0 {connected_to_start.contains(&(start_x, start_y - 1))} else {false},
+            connected_to_start.contains(&(start_x, start_y + 1)),
+            if start_x > 0 {connected_to_start.contains(&(start_x - 1, start_y))} else {false},
+            connected_to_start.contains(&(start_x + 1, start_y)),
+        );
+
+        match start_directions {
+            (true, true, false, false) => Ok(Tile::NS),
+            (false, false, true, true) => Ok(Tile::EW),
+            (true, false, false, true) => Ok(Tile::NE),
+            (true, false, true, false) => Ok(Tile::NW),
+            (false, true, true, false) => Ok(Tile::SW),
+            (false, true, false, true) => Ok(Tile::SE),
+            _ => Err(miette!("The start tile isn't on a loop")),
+        }
+    }
+
+    pub fn patch_start_pos(&mut self) -> miette::Result<()> {
+        let (start_x, start_y) = self.start_pos;
+        let start_replacement = self.start_tile()?;
+        self.tiles.set(start_x, start_y, start_replacement);
+        Ok(())
+    }
+}
+
+/// Distances along the loop when setting off from the start tile towards `start_pos`, without passing the start again
+pub fn walk_loop(world: &World, start_pos: (usize, usize)) -> BTreeMap<(usize, usize), usize> {
+    let distances = search::bfs_distances([start_pos], |&(x, y)| {
+        world.tiles[(x, y)].next_positions(x, y).into_iter()
+            // Only visit within bounds of map & valid tile types
+            .filter(|&pos| pos != world.start_pos)
+            .filter(|&(x, y)| !matches!(world.tiles.get(x, y), None | Some(Tile::Ground)))
+    });
+
+    distances.into_iter()
+        .map(|(pos, distance)| (pos, distance + 1))
+        .chain([(world.start_pos, 0)])
+        .collect()
+}
+
+/// The neighbours of the start tile whose pipes connect back to it
+pub fn find_start_positions(world: &World) -> Vec<(usize, usize)> {
+    let (start_x, start_y) = world.start_pos;
+
+    world.tiles.neighbours4(IVec2::new(start_x as i32, start_y as i32), Edges::Bounded)
+        .map(|(pos, tile)| ((pos.x as usize, pos.y as usize), tile))
+        .filter(|((x, y), tile)| tile.next_positions(*x, *y).contains(&world.start_pos))
+        .map(|(pos, _)| pos)
+        .collect()
+}
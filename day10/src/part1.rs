@@ -0,0 +1,92 @@
+use std::collections::BTreeMap;
+use crate::*;
+
+fn find_largest_distance(world: &World) -> ((usize, usize), usize) {
+    let (start_x, start_y) = world.start_pos;
+
+    let loop_start_positions = find_start_positions(world);
+
+    let walk_distances: Vec<BTreeMap<(usize, usize), usize>> = loop_start_positions.iter()
+        .map(|pos| walk_loop(world, *pos))
+        .collect();
+
+    let mut max_distances = BTreeMap::new();
+
+    for distances in walk_distances {
+        for ((x, y), d) in distances {
+            max_distances.entry((x, y))
+                .and_modify(|cur_best| { if d < *cur_best { *cur_best = d } })
+                .or_insert(d);
+        }
+    }
+
+    let mut best_dist = ((start_x, start_y), 0);
+    for ((x, y), d) in max_distances {
+        if d > best_dist.1 {
+            best_dist = ((x, y), d);
+        }
+    }
+    best_dist
+}
+
+pub fn solve(world: &World) -> miette::Result<usize> {
+    world.start_tile()?;
+    let (_, dist) = find_largest_distance(world);
+    Ok(dist)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = ".....
+.S-7.
+.|.|.
+.L-J.
+.....";
+
+        let world = World::new(input).unwrap();
+
+        let ((x, y), dist) = find_largest_distance(&world);
+        println!("{:?}", ((x, y), dist));
+        assert_eq!(dist, 4);
+    }
+
+    #[test]
+    fn test_input_2() {
+        let input = "..F7.
+.FJ|.
+SJ.L7
+|F--J
+LJ...";
+
+        let world = World::new(input).unwrap();
+
+        let ((x, y), dist) = find_largest_distance(&world);
+        println!("{:?}", ((x, y), dist));
+        assert_eq!(dist, 8);
+    }
+
+    #[test]
+    fn test_unknown_tile() {
+        let input = ".....
+.S-7.
+.|x|.
+.L-J.";
+
+        let err = World::new(input).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid tile \"x\" at line 3, column 3");
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::pipe_maze(seed, 41, 31);
+            assert_eq!(Day10::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use std::collections::BTreeSet;
+use crate::*;
+
+fn count_loop_crossings(world: &World, loop_positions: &BTreeSet<(usize, usize)>, start_pos: (usize, usize)) -> usize {
+    let (mut x, mut y) = start_pos;
+    let mut n_loop_crossings = 0;
+
+    while world.tiles.is_in_bounds(x, y) {
+        let next_pos = (x + 1, y);
+
+        if loop_positions.contains(&next_pos) {
+            let next_type = world.tiles.get(next_pos.0, next_pos.1);
+
+            match next_type {
+                Some(Tile::NE) | Some(Tile::NW) | Some(Tile::NS) => { n_loop_crossings += 1 }
+                Some(_other_tile) => (),
+                None => ()
+            }
+        }
+        (x, y) = next_pos;
+    }
+
+    n_loop_crossings
+}
+
+fn find_area_enclosed_by_loop(world: &World) -> miette::Result<usize> {
+    let mut world = world.clone();
+    world.patch_start_pos()?;
+
+    // The patched start connects to both of its neighbours on the loop
+    let start_position = find_start_positions(&world)[0];
+    let loop_positions = walk_loop(&world, start_position)
+        .keys()
+        .copied()
+        .collect::<BTreeSet<(usize, usize)>>();
+
+    let mut non_loop_positions = BTreeSet::new();
+    for x in 0..world.tiles.width {
+        for y in 0..world.tiles.height {
+            if !loop_positions.contains(&(x, y)) {
+                non_loop_positions.insert((x, y));
+            }
+        }
+    }
+
+    Ok(non_loop_positions
+        .iter().copied()
+        .map(|(x, y)| {
+            count_loop_crossings(&world, &loop_positions, (x, y)) % 2
+        })
+        .sum())
+}
+
+pub fn solve(world: &World) -> miette::Result<usize> {
+    find_area_enclosed_by_loop(world)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use super::*;
+
+    #[test]
+    fn test_loop_crossings() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+        let mut world = World::new(input).unwrap();
+        world.patch_start_pos().unwrap();
+
+        let start_position = find_start_positions(&world)[0];
+        let loop_positions = walk_loop(&world, start_position)
+            .keys()
+            .copied()
+            .collect::<BTreeSet<(usize, usize)>>();
+
+        assert_eq!(count_loop_crossings(&world, &loop_positions, (0, 0)), 0);
+        assert_eq!(count_loop_crossings(&world, &loop_positions, (0, 1)), 0);
+        assert_eq!(count_loop_crossings(&world, &loop_positions, (0, 2)), 2);
+        assert_eq!(count_loop_crossings(&world, &loop_positions, (0, 3)), 4);
+    }
+
+    #[test]
+    fn test_input_1() {
+        let input = "...........
+.S-------7.
+.|F-----7|.
+.||.....||.
+.||.....||.
+.|L-7.F-J|.
+.|..|.|..|.
+.L--J.L--J.
+...........";
+
+        let num_inside = find_area_enclosed_by_loop(&World::new(input).unwrap()).unwrap();
+
+        assert_eq!(num_inside, 4);
+    }
+
+    #[test]
+    fn test_input_2() {
+        let input = ".F----7F7F7F7F-7....
+.|F--7||||||||FJ....
+.||.FJ||||||||L7....
+FJL7L7LJLJ||LJ.L-7..
+L--J.L7...LJS7F-7L7.
+....F-J..F7FJ|L7L7L7
+....L7.F7||L7|.L7L7|
+.....|FJLJ|FJ|F7|.LJ
+....FJL-7.||.||||...
+....L---J.LJ.LJLJ...";
+
+        let num_inside = find_area_enclosed_by_loop(&World::new(input).unwrap()).unwrap();
+        assert_eq!(num_inside, 8);
+    }
+
+    #[test]
+    fn test_input_3() {
+        let input = "FF7FSF7F7F7F7F7F---7
+L|LJ||||||||||||F--J
+FL-7LJLJ||||||LJL-77
+F--JF--7||LJLJ7F7FJ-
+L---JF-JLJ.||-FJLJJ7
+|F|F-JF---7F7-L7L|7|
+|FFJF7L7F-JF7|JL---7
+7-L-JL7||F7|L7F-7F7|
+L.L7LFJ|||||FJL7||LJ
+L7JLJL-JLJLJL--JLJ.L";
+
+        let num_inside = find_area_enclosed_by_loop(&World::new(input).unwrap()).unwrap();
+        assert_eq!(num_inside, 10);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::pipe_maze(seed, 41, 31);
+            assert_eq!(Day10::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
+
+    #[test]
+    fn test_start_off_loop() {
+        // Alone, cut off from the loop, and with three pipes joining it
+        for input in ["S", ".....\n.S.7.\n.|.|.\n.L-J.\n.....", "-S-\n.|."] {
+            let err = Day10::solve_part2(input).unwrap_err();
+            assert_eq!(err.to_string(), "The start tile isn't on a loop", "{}", input);
+            assert!(Day10::solve_part1(input).is_err(), "{}", input);
+        }
+    }
+}
@@ -1,72 +1,36 @@
-use std::slice::Chunks;
 use glam::U64Vec2;
+use aoc2023::error::ParseError;
+use aoc2023::map2d::Map2D;
+use aoc2023::Solution;
 
-pub struct World {
-    buf: Vec<char>,
-    width: usize,
-    height: usize,
-}
-
-impl World {
-    pub fn new(input: &str) -> Self {
-        let mut buf = Vec::new();
-        let mut width = 0;
-        let mut height = 0;
-
-        for (y, line) in input.lines().enumerate() {
-            height = y + 1;
-            for (x, c) in line.chars().enumerate() {
-                width = x + 1;
-                buf.push(c);
-            }
-        }
+pub mod part1;
+pub mod part2;
 
-        Self {
-            buf,
-            width,
-            height,
-        }
-    }
+pub struct Day11;
 
-    pub fn is_in_bounds(&self, x: usize, y: usize) -> bool {
-        x < self.width && y < self.height
-    }
+impl Solution for Day11 {
+    type Parsed<'a> = Map2D<char>;
+    type Answer1 = u64;
+    type Answer2 = u64;
 
-    pub fn get(&self, x: usize, y: usize) -> Option<char> {
-        match (x, y) {
-            (x, y) if self.is_in_bounds(x, y) => {
-                self.buf.get(x + y * self.width).copied()
-            }
-            _ => None
-        }
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
     }
 
-    pub fn set(&mut self, x: usize, y: usize, c: char) {
-        if !self.is_in_bounds(x, y) { panic!("out of bounds") }
-        self.buf[x + y * self.width] = c;
+    fn part1(world: &Self::Parsed<'_>) -> miette::Result<u64> {
+        Ok(part1::solve(world))
     }
 
-    pub fn iter_rows(&self) -> Chunks<'_, char> {
-        self.buf.chunks(self.width)
+    fn part2(world: &Self::Parsed<'_>) -> miette::Result<u64> {
+        Ok(part2::solve(world))
     }
+}
 
-    pub fn iter_cols(&'_ self) -> impl Iterator<Item = impl Iterator<Item = char> + '_> {
-        (0..self.width).map(move |col_start| {
-            let mut next_index = col_start;
-            std::iter::from_fn(move || {
-                if next_index < self.buf.len() {
-                    let current = self.buf[next_index];
-                    next_index += self.width;
-                    Some(current)
-                } else {
-                    None
-                }
-            })
-        })
-    }
+pub fn parse(input: &str) -> Result<Map2D<char>, ParseError> {
+    Map2D::parse(input, |c| ".#".contains(c).then_some(c))
 }
 
-pub fn find_galaxies(world: &World, expansion_factor: usize) -> Vec<U64Vec2> {
+pub fn find_galaxies(world: &Map2D<char>, expansion_factor: usize) -> Vec<U64Vec2> {
     if expansion_factor < 1 {
         panic!("Expansion factor must be > 0");
     }
@@ -138,7 +102,7 @@ mod tests {
     #[test]
     fn test_iter_rows() {
         let input = INPUT_1;
-        let map = World::new(input);
+        let map = parse(input).unwrap();
         let mut rows = map.iter_rows();
 
         assert_eq!(rows.next().unwrap(), ['.', '.', '.', '#', '.', '.', '.', '.', '.', '.']);
@@ -157,7 +121,7 @@ mod tests {
     #[test]
     fn test_iter_cols() {
         let input = INPUT_1;
-        let map = World::new(input);
+        let map = parse(input).unwrap();
         let mut cols = map.iter_cols();
 
         assert_eq!(cols.next().unwrap().collect::<Vec<_>>(), ['.', '.', '#', '.', '.', '.', '.', '.', '.', '#']);
@@ -176,7 +140,7 @@ mod tests {
     #[test]
     fn test_find_galaxies_1() {
         let input = INPUT_1;
-        let map = World::new(input);
+        let map = parse(input).unwrap();
         let galaxies = find_galaxies(&map, 2);
 
         let expected = [(4, 0), (9, 1), (0, 2), (8, 5), (1, 6), (12, 7), (9, 10), (0, 11), (5, 11)].iter()
@@ -1,17 +1,13 @@
 use itertools::Itertools;
-use day11::*;
+use crate::*;
 
-fn main() {
-    let input = include_str!("../input1.txt");
-    let world = World::new(input);
-    let galaxies = find_galaxies(&world, 2);
+pub fn solve(world: &Map2D<char>) -> u64 {
+    let galaxies = find_galaxies(world, 2);
 
-    let result: u64 = galaxies.iter()
+    galaxies.iter()
         .combinations_with_replacement(2)
         .map(|combination| manhattan_distance(*combination[0], *combination[1]))
-        .sum();
-
-    println!("Result: {}", result);
+        .sum()
 }
 
 #[cfg(test)]
@@ -33,7 +29,7 @@ mod tests {
     #[test]
     fn test_input_1 () {
         let input = INPUT_1;
-        let world = World::new(input);
+        let world = parse(input).unwrap();
         let galaxies = find_galaxies(&world, 2);
 
         let result: u64 = galaxies.iter()
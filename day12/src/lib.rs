@@ -1,5 +1,31 @@
 use std::collections::HashMap;
 use itertools::Itertools;
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Vec<(&'a str, Vec<usize>)>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse::lines(input, parse_line).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(rows: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part1::solve(rows))
+    }
+
+    fn part2(rows: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(rows))
+    }
+}
 
 pub fn count(records: &str, groups: &[usize], cur_count: usize) -> usize {
     let mut cache = HashMap::new();
@@ -45,32 +71,87 @@ pub fn count(records: &str, groups: &[usize], cur_count: usize) -> usize {
     inner(records, groups, cur_count, &mut cache)
 }
 
-pub fn parse_line(line: &str, part2: bool) -> (String, Vec<usize>) {
-    let (records, groups) = line.split_whitespace().collect_tuple().unwrap();
+pub fn parse_line(line: &str) -> Result<(&str, Vec<usize>), ParseError> {
+    let (records, groups) = line.split_whitespace().collect_tuple()
+        .ok_or_else(|| ParseError::expected("records and group sizes", line, line))?;
+
+    if let Some(idx) = records.find(|c| !"?.#".contains(c)) {
+        let len = records[idx..].chars().next().map_or(1, char::len_utf8);
+        return Err(ParseError::invalid("record", line, &records[idx..idx + len]));
+    }
+
+    let groups = groups.split(',')
+        .map(|el| parse::number(line, el))
+        .collect::<Result<Vec<usize>, _>>()?;
+
+    Ok((records, groups))
+}
 
-    let mut records = if part2 {
-        [records; 5].join("?")
-    } else {
-        String::from(records)
-    };
+pub fn unfold(records: &str, groups: &[usize], times: usize) -> (String, Vec<usize>) {
+    let mut records = vec![records; times].join("?");
 
     if !records.ends_with('.') {
         records.push('.')
     }
 
-    let groups = groups.split(',')
-        .map(|el| el.parse().unwrap())
-        .collect::<Vec<usize>>();
-
-    let groups = groups.repeat(if part2 { 5 } else { 1 }).to_vec();
+    (records, groups.repeat(times))
+}
 
-    (records, groups)
+pub fn count_arrangements(rows: &[(&str, Vec<usize>)], times: usize) -> usize {
+    rows.iter()
+        .map(|(records, groups)| unfold(records, groups, times))
+        .map(|(records, groups)| count(&records, &groups, 0))
+        .sum::<usize>()
 }
 
 #[cfg(test)]
 mod test {
+    use proptest::prelude::*;
     use super::*;
 
+    /// Counts arrangements by trying every assignment of the unknown springs
+    fn brute_force(records: &str, groups: &[usize]) -> usize {
+        let unknown = records.match_indices('?').map(|(idx, _)| idx).collect::<Vec<_>>();
+
+        (0..1u32 << unknown.len())
+            .filter(|assignment| {
+                let mut candidate = records.as_bytes().to_vec();
+                for (bit, &idx) in unknown.iter().enumerate() {
+                    candidate[idx] = if assignment & (1 << bit) != 0 { b'#' } else { b'.' };
+                }
+                let damaged = candidate.split(|&spring| spring != b'#')
+                    .filter(|group| !group.is_empty())
+                    .map(|group| group.len())
+                    .collect::<Vec<_>>();
+                damaged == groups
+            })
+            .count()
+    }
+
+    /// Records with at most `max_unknown` unknown springs, and group sizes that are usually possible
+    fn row(max_len: usize, max_unknown: usize) -> impl Strategy<Value=(String, Vec<usize>)> {
+        let groups = prop::collection::vec(1..5usize, 1..4);
+        let records = prop::collection::vec(prop::sample::select(vec!['.', '#', '?']), 1..=max_len)
+            .prop_filter("too many unknown springs", move |springs| springs.iter().filter(|&&c| c == '?').count() <= max_unknown)
+            .prop_map(|springs| springs.into_iter().collect::<String>());
+        (records, groups)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_count_matches_brute_force((records, groups) in row(14, 12)) {
+            let (unfolded, unfolded_groups) = unfold(&records, &groups, 1);
+            prop_assert_eq!(count(&unfolded, &unfolded_groups, 0), brute_force(&records, &groups));
+        }
+
+        #[test]
+        fn prop_unfolded_count_matches_brute_force((records, groups) in row(6, 5)) {
+            let (unfolded, unfolded_groups) = unfold(&records, &groups, 2);
+            let joined = format!("{}?{}", records, records);
+            prop_assert_eq!(count(&unfolded, &unfolded_groups, 0), brute_force(&joined, &unfolded_groups));
+        }
+    }
+
     #[test]
     fn test_known() {
         assert_eq!(count("#.#.###", &[1, 1, 3], 0), 1);
@@ -0,0 +1,33 @@
+use crate::*;
+
+pub fn solve(rows: &[(&str, Vec<usize>)]) -> usize {
+    count_arrangements(rows, 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use super::*;
+
+    #[test]
+    fn test_example_1() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        let rows = Day12::parse(input).unwrap();
+        let result = count_arrangements(&rows, 1);
+
+        assert_eq!(result, 21);
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::spring_records(12, 200, 20);
+        assert_eq!(Day12::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap());
+    }
+}
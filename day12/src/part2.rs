@@ -0,0 +1,28 @@
+use crate::*;
+
+pub fn solve(rows: &[(&str, Vec<usize>)]) -> usize {
+    count_arrangements(rows, 5)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use super::*;
+
+    #[test]
+    fn test_example_2() {
+        let input = "???.### 1,1,3
+.??..??...?##. 1,1,3
+?#?#?#?#?#?#?#? 1,3,1,6
+????.#...#... 4,1,1
+????.######..#####. 1,6,5
+?###???????? 3,2,1";
+
+        let rows = Day12::parse(input).unwrap();
+        let result = count_arrangements(&rows, 5);
+
+        assert_eq!(result, 525152);
+    }
+
+
+}
\ No newline at end of file
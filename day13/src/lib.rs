@@ -0,0 +1,77 @@
+use aoc2023::error::ParseError;
+use aoc2023::map2d::Map2D;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Parsed<'a> = Vec<Map2D<char>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        input.split("\n\n")
+            .map(|pattern| parse_pattern(pattern).map_err(|err| err.within(input, pattern)))
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.with_source(input))
+    }
+
+    fn part1(worlds: &Self::Parsed<'_>) -> miette::Result<usize> {
+        part1::solve(worlds)
+    }
+
+    fn part2(worlds: &Self::Parsed<'_>) -> miette::Result<usize> {
+        part2::solve(worlds)
+    }
+}
+
+pub fn parse_pattern(input: &str) -> Result<Map2D<char>, ParseError> {
+    Map2D::parse(input, |c| ".#".contains(c).then_some(c))
+}
+
+/// Finds a horizontal mirror line with exactly `smudges` tiles that don't match their reflection,
+/// returning the number of rows above it
+pub fn find_mirror(world: &Map2D<char>, smudges: usize) -> Option<usize> {
+    let flipped = world.flip_vertical();
+
+    (1..world.height).find(|&split| {
+        let rows = split.min(world.height - split);
+
+        // Row `split + i` is reflected onto row `split - 1 - i`, which is row `height - split + i` once flipped
+        let below = world.view(0, split, world.width, rows);
+        let above = flipped.view(0, world.height - split, world.width, rows);
+
+        below.iter().zip(above.iter())
+            .filter(|((_, a), (_, b))| a != b)
+            .count() == smudges
+    })
+}
+
+/// Columns left of the vertical mirror plus 100 times the rows above the horizontal one.
+/// Vertical mirrors are found as horizontal ones in the transposed pattern.
+pub fn summarize(world: &Map2D<char>, smudges: usize) -> Option<usize> {
+    match (find_mirror(&world.transpose(), smudges), find_mirror(world, smudges)) {
+        (None, None) => None,
+        (v, h) => Some(v.unwrap_or(0) + 100 * h.unwrap_or(0)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_mirror() {
+        let world = parse_pattern("#..\n.##\n.##\n#..\n##.").unwrap();
+        assert_eq!(find_mirror(&world, 0), Some(2));
+        assert_eq!(find_mirror(&world.transpose(), 0), None);
+
+        let world = parse_pattern("#.#\n#.#\n###").unwrap();
+        assert_eq!(find_mirror(&world, 0), Some(1));
+        assert_eq!(find_mirror(&world, 1), Some(2));
+        assert_eq!(find_mirror(&world, 2), None);
+    }
+}
@@ -0,0 +1,42 @@
+use miette::miette;
+use aoc2023::map2d::Map2D;
+use crate::summarize;
+
+pub fn solve(worlds: &[Map2D<char>]) -> miette::Result<usize> {
+    worlds.iter().enumerate()
+        .map(|(idx, world)| summarize(world, 0).ok_or_else(|| miette!("No mirror found for world {}", idx)))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2023::Solution;
+    use crate::Day13;
+
+    #[test]
+    fn test_example_1() {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+        assert_eq!(Day13::solve_part1(input).unwrap(), 405)
+    }
+
+    #[test]
+    fn test_no_mirror() {
+        assert_eq!(Day13::solve_part1("").unwrap_err().to_string(), "No mirror found for world 0");
+        assert_eq!(Day13::solve_part1("##\n##\n\n#.\n.#").unwrap_err().to_string(), "No mirror found for world 1");
+    }
+}
\ No newline at end of file
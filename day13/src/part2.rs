@@ -0,0 +1,47 @@
+use miette::miette;
+use aoc2023::map2d::Map2D;
+use crate::summarize;
+
+pub fn solve(worlds: &[Map2D<char>]) -> miette::Result<usize> {
+    worlds.iter().enumerate()
+        .map(|(idx, world)| summarize(world, 1).ok_or_else(|| miette!("No smudged mirror found for world {}", idx)))
+        .sum()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use crate::Day13;
+
+    #[test]
+    fn test_example_1() {
+        let input = "#.##..##.
+..#.##.#.
+##......#
+##......#
+..#.##.#.
+..##..##.
+#.#.##.#.
+
+#...##..#
+#....#..#
+..##..###
+#####.##.
+#####.##.
+..##..###
+#....#..#";
+
+        assert_eq!(Day13::solve_part2(input).unwrap(), 400)
+    }
+
+    #[test]
+    fn test_generated() {
+        // Every pattern has both kinds of reflection, so neither part runs out of mirrors
+        for seed in 0..20 {
+            let puzzle = gen::mirror_patterns(seed, 100, 17);
+            assert!(Day13::solve_part1(&puzzle.input).is_ok(), "{}", puzzle.input);
+            assert!(Day13::solve_part2(&puzzle.input).is_ok(), "{}", puzzle.input);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use aoc2023::error::ParseError;
+use aoc2023::map2d::Map2D;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Parsed<'a> = Map2D<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part1::solve(world))
+    }
+
+    fn part2(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(world))
+    }
+}
+
+pub fn parse(input: &str) -> Result<Map2D<char>, ParseError> {
+    Map2D::parse(input, |c| ".#O".contains(c).then_some(c))
+}
+
+/// Rolls every round rock as far north as it goes
+pub fn tilt_north(world: &mut Map2D<char>) {
+    for x in 0..world.width {
+        // The row the next rock in this column comes to rest on
+        let mut free = 0;
+
+        for y in 0..world.height {
+            match world[(x, y)] {
+                '#' => free = y + 1,
+                'O' => {
+                    world[(x, y)] = '.';
+                    world[(x, free)] = 'O';
+                    free += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+pub fn calc_load(world: &Map2D<char>) -> usize {
+    world.iter_rows().enumerate()
+        .map(|(y, row)| {
+            let multiplier = world.height - y;
+            row.iter().filter(|&&c| c == 'O').count() * multiplier
+        })
+        .sum()
+}
@@ -0,0 +1,43 @@
+use aoc2023::map2d::Map2D;
+use crate::{calc_load, tilt_north};
+
+pub fn solve(world: &Map2D<char>) -> usize {
+    let mut world = world.clone();
+    tilt_north(&mut world);
+    calc_load(&world)
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day14;
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let mut world = crate::parse(input).unwrap();
+        tilt_north(&mut world);
+        let result = calc_load(&world);
+
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::platform(14, 100);
+        assert!(Day14::solve_part1(&puzzle.input).is_ok());
+        assert!(Day14::solve_part2(&puzzle.input).is_ok());
+    }
+}
@@ -0,0 +1,88 @@
+use aoc2023::map2d::Map2D;
+use crate::{calc_load, tilt_north};
+
+/// Tilts north, west, south and east. Each tilt is done north, then the map is turned
+/// clockwise so the next direction faces north; after four turns it's back the right way up.
+fn do_cycle(world: &mut Map2D<char>) {
+    for _ in 0..4 {
+        tilt_north(world);
+        *world = world.rotate_cw();
+    }
+}
+
+fn find_period(signal: &[usize], n: usize) -> usize {
+    let mut results = Vec::new();
+    for offset in 0..n {
+        let matches = (0..(signal.len() - offset))
+            .all(|i| signal[i] == signal[i + offset]);
+        if matches {results.push(offset)}
+    }
+    results[1]
+}
+
+fn run_cycles(world: &mut Map2D<char>, n_cycles: usize) -> usize {
+    let n_settle = 160;
+    let n_capture = 30;
+
+    // Run for a few cycles to settle
+    for _cycle in 0..n_settle {
+        do_cycle(world);
+    }
+
+    // Capture sequence
+    let sequence = (0..n_capture)
+        .map(|_| {
+            do_cycle(world);
+            calc_load(world)
+        })
+        .collect::<Vec<_>>();
+
+    let period = find_period(&sequence, n_capture);
+
+    let leftover_cycles = n_cycles - n_capture - n_settle;
+    let idx = leftover_cycles % period;
+
+    for _cycle in 0..idx {
+        do_cycle(world);
+    }
+
+    calc_load(world)
+}
+
+
+pub fn solve(world: &Map2D<char>) -> usize {
+    let mut world = world.clone();
+    run_cycles(&mut world, 1000000000)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "O....#....
+O.OO#....#
+.....##...
+OO.#O....O
+.O.....O#.
+O.#..O.#.#
+..O..#O..O
+.......O..
+#....###..
+#OO..#....";
+
+        let mut world = crate::parse(input).unwrap();
+        let result = run_cycles(&mut world, 1000000000);
+
+        assert_eq!(result, 64);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut world = crate::parse("O..\n.#.\n..O").unwrap();
+        do_cycle(&mut world);
+
+        assert_eq!(world, crate::parse("..O\n.#.\n..O").unwrap());
+    }
+}
\ No newline at end of file
@@ -0,0 +1,110 @@
+#![feature(hasher_prefixfree_extras)]
+#![feature(linked_list_remove)]
+
+use std::hash::{Hash, Hasher};
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Parsed<'a> = Vec<Step<'a>>;
+    type Answer1 = u64;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        input.strip_suffix('\n')
+            .unwrap_or(input)
+            .split(',')
+            .map(|step| Step::new(step).map_err(|err| err.within(input, step)))
+            .collect::<Result<_, _>>()
+            .map_err(|err| err.with_source(input))
+    }
+
+    fn part1(steps: &Self::Parsed<'_>) -> miette::Result<u64> {
+        Ok(part1::solve(steps))
+    }
+
+    fn part2(steps: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(steps))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Operation {
+    /// Puts a lens with the given focal length into the label's box
+    Insert(usize),
+    Remove,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Step<'a> {
+    pub text: &'a str,
+    pub label: &'a str,
+    pub operation: Operation,
+}
+
+impl<'a> Step<'a> {
+    pub fn new(text: &'a str) -> Result<Self, ParseError> {
+        if !text.is_ascii() {
+            return Err(ParseError::invalid("step", text, text));
+        }
+
+        let (label, operation) = match text.strip_suffix('-') {
+            Some(label) => (label, Operation::Remove),
+            None => {
+                let (label, focal_length) = text.split_once('=')
+                    .ok_or_else(|| ParseError::expected("'=' or a trailing '-'", text, text))?;
+                (label, Operation::Insert(parse::number(text, focal_length)?))
+            }
+        };
+
+        Ok(Self {
+            text,
+            label,
+            operation,
+        })
+    }
+}
+
+#[derive(Default)]
+pub struct HolidayHasher {
+    state: u64,
+}
+
+impl HolidayHasher {
+    pub fn new() -> Self {
+        Self {state: 0}
+    }
+}
+
+impl Hasher for HolidayHasher {
+    fn finish(&self) -> u64 {
+        self.state
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state += *byte as u64;
+            self.state *= 17;
+            self.state %= 256;
+        }
+    }
+
+    fn write_str(&mut self, s: &str) {
+        if !s.is_ascii() {
+            panic!("Not an ascii string")
+        }
+        self.write(s.as_bytes());
+    }
+}
+
+pub fn get_hash(input: &str) -> u64 {
+    let mut hasher = HolidayHasher::new();
+    input.hash(&mut hasher);
+    hasher.finish()
+}
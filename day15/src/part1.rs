@@ -0,0 +1,56 @@
+use crate::*;
+
+pub fn solve(steps: &[Step]) -> u64 {
+    steps.iter()
+        .map(|step| get_hash(step.text))
+        .sum::<u64>()
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day15;
+    use std::hash::{Hash, Hasher};
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+        let result = input.split(',')
+            .map(|part| {
+                let mut hasher = HolidayHasher::new();
+                part.hash(&mut hasher);
+                hasher.finish()
+            })
+            .sum::<u64>();
+        assert_eq!(result, 1320);
+    }
+    #[test]
+    fn test_hasher_1() {
+        let mut hasher = HolidayHasher::new();
+        "rn=1".hash(&mut hasher);
+        assert_eq!(hasher.finish(), 30);
+    }
+
+    #[test]
+    fn test_hasher_2() {
+        let mut hasher = HolidayHasher::new();
+        "cm-".hash(&mut hasher);
+        assert_eq!(hasher.finish(), 253);
+    }
+
+    #[test]
+    fn test_hasher_3() {
+        let mut hasher = HolidayHasher::new();
+        "qp=3".hash(&mut hasher);
+        assert_eq!(hasher.finish(), 97);
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::init_sequence(15, 4000, 500);
+        assert!(Day15::solve_part1(&puzzle.input).is_ok());
+        assert!(Day15::solve_part2(&puzzle.input).is_ok());
+    }
+}
@@ -1,75 +1,24 @@
-#![feature(ascii_char)]
-#![feature(hasher_prefixfree_extras)]
-#![feature(linked_list_remove)]
-
 use std::collections::{HashMap, LinkedList};
-use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::hash::BuildHasherDefault;
 use itertools::Itertools;
+use crate::*;
 
-#[derive(Default)]
-struct HolidayHasher {
-    state: u64,
-}
-
-impl HolidayHasher {
-    fn new() -> Self {
-        Self { state: 0 }
-    }
-}
-
-impl Hasher for HolidayHasher {
-    fn finish(&self) -> u64 {
-        self.state
-    }
-
-    fn write(&mut self, bytes: &[u8]) {
-        for byte in bytes {
-            self.state += *byte as u64;
-            self.state *= 17;
-            self.state %= 256;
-        }
-    }
-
-    fn write_str(&mut self, s: &str) {
-        if !s.is_ascii() {
-            panic!("Not an ascii string")
-        }
-        self.write(s.as_bytes());
-    }
-}
-
-fn main() {
-    let input = include_str!("../input1.txt");
-    let boxes = initialization_sequence(input);
-    let result = boxes.iter()
+pub fn solve(steps: &[Step]) -> usize {
+    let boxes = initialization_sequence(steps);
+    boxes.iter()
         .map(|(idx, contents)| {
             (*idx as usize + 1) * contents.iter().enumerate()
                 .map(|(i, (_, f))| (i + 1) * f).sum::<usize>()
-        }).sum::<usize>();
-    println!("Result: {}", result);
+        }).sum::<usize>()
 }
 
 type HolidayHashMap<K, V> = HashMap<K, V, BuildHasherDefault<HolidayHasher>>;
 
-fn get_hash(input: &str) -> u64 {
-    let mut hasher = HolidayHasher::new();
-    input.hash(&mut hasher);
-    hasher.finish()
-}
-
-fn initialization_sequence(input: &str) -> HashMap<u64, LinkedList<(&str, usize)>, BuildHasherDefault<HolidayHasher>> {
-    let instructions = input.strip_suffix('\n')
-        .unwrap_or(input)
-        .split(',')
-        .collect::<Vec<_>>();
-
+fn initialization_sequence<'a>(instructions: &[Step<'a>]) -> HolidayHashMap<u64, LinkedList<(&'a str, usize)>> {
     let mut boxes = HolidayHashMap::default();
 
-    for instruction in instructions {
-        if instruction.contains('=') {
-            let (label, number) = instruction.split('=').collect_tuple().unwrap();
-            let number = number.parse::<usize>().unwrap();
-
+    for &Step { label, operation, .. } in instructions {
+        if let Operation::Insert(number) = operation {
             boxes.entry(get_hash(label))
                 .and_modify(|box_contents: &mut LinkedList<(_, _)>| {
                     if let Some(x) = box_contents.iter_mut().find(|el| el.0 == label) {
@@ -81,8 +30,7 @@ fn initialization_sequence(input: &str) -> HashMap<u64, LinkedList<(&str, usize)
                 .or_insert(LinkedList::from([(label, number)]));
         }
 
-        if instruction.contains('-') {
-            let (label, _) = instruction.split('-').collect_tuple().unwrap();
+        if operation == Operation::Remove {
             boxes.entry(get_hash(label))
                 .and_modify(|box_contents| {
                     if let Some((idx, _)) = box_contents.iter().find_position(|el| el.0 == label) {
@@ -97,13 +45,14 @@ fn initialization_sequence(input: &str) -> HashMap<u64, LinkedList<(&str, usize)
 
 #[cfg(test)]
 mod test {
-    use std::hash::Hash;
+    use std::hash::{Hash, Hasher};
+    use aoc2023::Solution;
     use super::*;
 
     #[test]
     fn test_input_1() {
         let input = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
-        let boxes = initialization_sequence(input);
+        let boxes = initialization_sequence(&Day15::parse(input).unwrap());
         let result = boxes.iter()
             .map(|(idx, contents)| {
                 (*idx as usize + 1) * contents.iter().enumerate().map(|(i, (_, f))| (i + 1) * f).sum::<usize>()
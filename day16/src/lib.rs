@@ -1,65 +1,118 @@
-use std::collections::{HashSet, VecDeque};
+use std::fmt::{Display, Formatter};
 use glam::IVec2;
 use itertools::Itertools;
-use rustc_hash::FxHashSet;
 use aoc2023::direction::Direction;
-use aoc2023::world::World;
+use aoc2023::error::ParseError;
+use aoc2023::map2d::Map2D;
+use aoc2023::search;
+use aoc2023::Solution;
 
-pub fn walk(world: &World, start_pos: IVec2, start_direction: Direction) -> Vec<IVec2> {
-    let mut visited = FxHashSet::default();
-    let mut to_visit = VecDeque::new();
-    to_visit.push_back((start_pos, start_direction));
+pub mod part1;
+pub mod part2;
 
-    while let Some((pos, direction)) = to_visit.pop_front() {
+pub struct Day16;
 
-        if visited.contains(&(pos, direction)) {
-            continue;
-        }
+impl Solution for Day16 {
+    type Parsed<'a> = Map2D<Tile>;
+    type Answer1 = usize;
+    type Answer2 = usize;
 
-        if let Some(tile) = world.get(pos.x as usize, pos.y as usize) {
-            visited.insert((pos, direction));
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
 
-            match tile {
-                '.' => to_visit.push_back((pos + direction.as_delta(), direction)),
-                '/' => {
-                    let new_direction = match direction {
-                        Direction::North => Direction::East,
-                        Direction::East => Direction::North,
-                        Direction::South => Direction::West,
-                        Direction::West => Direction::South
-                    };
-                    to_visit.push_back((pos + new_direction.as_delta(), new_direction));
-                }
-                '\\' => {
-                    let new_direction = match direction {
-                        Direction::North => Direction::West,
-                        Direction::East => Direction::South,
-                        Direction::South => Direction::East,
-                        Direction::West => Direction::North
-                    };
-                    to_visit.push_back((pos + new_direction.as_delta(), new_direction));
-                }
-                '-' => match direction {
-                    Direction::East | Direction::West => to_visit.push_back((pos + direction.as_delta(), direction)),
-                    Direction::North | Direction::South => {
-                        to_visit.push_back((pos + Direction::East.as_delta(), Direction::East));
-                        to_visit.push_back((pos + Direction::West.as_delta(), Direction::West));
-                    }
-                },
-                '|' => match direction {
-                    Direction::North | Direction::South => to_visit.push_back((pos + direction.as_delta(), direction)),
-                    Direction::East | Direction::West => {
-                        to_visit.push_back((pos + Direction::North.as_delta(), Direction::North));
-                        to_visit.push_back((pos + Direction::South.as_delta(), Direction::South));
-                    }
-                }
-                c => panic!("Unknown tile: {}", c)
-            }
+    fn part1(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part1::solve(world))
+    }
+
+    fn part2(world: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(world))
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Tile {
+    Empty,
+    /// `/`
+    ForwardMirror,
+    /// `\\`
+    BackMirror,
+    /// `-`
+    HorizontalSplitter,
+    /// `|`
+    VerticalSplitter,
+}
+
+impl Tile {
+    pub fn new(c: char) -> Option<Self> {
+        match c {
+            '.' => Some(Self::Empty),
+            '/' => Some(Self::ForwardMirror),
+            '\\' => Some(Self::BackMirror),
+            '-' => Some(Self::HorizontalSplitter),
+            '|' => Some(Self::VerticalSplitter),
+            _ => None
         }
     }
 
-    visited.iter().copied()
+    pub fn as_char(&self) -> char {
+        match self {
+            Self::Empty => '.',
+            Self::ForwardMirror => '/',
+            Self::BackMirror => '\\',
+            Self::HorizontalSplitter => '-',
+            Self::VerticalSplitter => '|',
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_char())
+    }
+}
+
+/// Parses the contraption, which needs at least one tile for the beam to enter
+pub fn parse(input: &str) -> Result<Map2D<Tile>, ParseError> {
+    let world = Map2D::parse(input, Tile::new)?;
+    if world.width == 0 || world.height == 0 {
+        return Err(ParseError::expected_at_end("a tile", input));
+    }
+    Ok(world)
+}
+
+/// The directions a beam heading in `direction` leaves `tile` in
+pub fn deflect(tile: Tile, direction: Direction) -> &'static [Direction] {
+    match (tile, direction) {
+        (Tile::ForwardMirror, Direction::North) => &[Direction::East],
+        (Tile::ForwardMirror, Direction::East) => &[Direction::North],
+        (Tile::ForwardMirror, Direction::South) => &[Direction::West],
+        (Tile::ForwardMirror, Direction::West) => &[Direction::South],
+        (Tile::BackMirror, Direction::North) => &[Direction::West],
+        (Tile::BackMirror, Direction::East) => &[Direction::South],
+        (Tile::BackMirror, Direction::South) => &[Direction::East],
+        (Tile::BackMirror, Direction::West) => &[Direction::North],
+        (Tile::HorizontalSplitter, Direction::North | Direction::South) => &[Direction::East, Direction::West],
+        (Tile::VerticalSplitter, Direction::East | Direction::West) => &[Direction::North, Direction::South],
+        (Tile::Empty | Tile::HorizontalSplitter | Tile::VerticalSplitter, _) => match direction {
+            Direction::North => &[Direction::North],
+            Direction::East => &[Direction::East],
+            Direction::South => &[Direction::South],
+            Direction::West => &[Direction::West],
+        },
+    }
+}
+
+/// The tiles energized by a beam entering at `start_pos`, which has to be on the map
+pub fn walk(world: &Map2D<Tile>, start_pos: IVec2, start_direction: Direction) -> Vec<IVec2> {
+    let beams = search::bfs_distances([(start_pos, start_direction)], |&(pos, direction)| {
+        deflect(world[(pos.x as usize, pos.y as usize)], direction).iter()
+            .map(move |&next_direction| (pos + next_direction.as_delta(), next_direction))
+            .filter(|(next_pos, _)| world.contains(*next_pos))
+    });
+
+    beams.into_keys()
         .map(|(pos, _dir)| pos)
         .unique()
         .collect()
-}
\ No newline at end of file
+}
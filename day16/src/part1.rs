@@ -1,13 +1,10 @@
 use glam::IVec2;
 use aoc2023::direction::Direction;
-use aoc2023::world::World;
-use day16::walk;
+use aoc2023::map2d::Map2D;
+use crate::{walk, Tile};
 
-fn main() {
-    let input = include_str!("../input1.txt");
-    let world = World::new(input);
-    let visited =  walk(&world, IVec2::new(0, 0), Direction::East);
-    println!("Result: {}", visited.len());
+pub fn solve(world: &Map2D<Tile>) -> usize {
+    walk(world, IVec2::new(0, 0), Direction::East).len()
 }
 
 #[cfg(test)]
@@ -29,10 +26,10 @@ mod test {
 .|....-|.\
 ..//.|....";
 
-        let world = World::new(input);
+        let world = crate::parse(input).unwrap();
         let visited = walk(&world, IVec2::new(0, 0), Direction::East);
 
-        let mut world_visited = World::from_size(world.width, world.height, '.');
+        let mut world_visited = Map2D::from_size(world.width, world.height, '.');
         for pos in &visited {
             world_visited.set(pos.x as usize, pos.y as usize, '#');
         }
@@ -40,4 +37,10 @@ mod test {
 
         assert_eq!(visited.len(), 46)
     }
+
+    #[test]
+    fn test_malformed() {
+        assert_eq!(crate::parse("").unwrap_err().to_string(), "Expected a tile at line 1, column 1");
+        assert_eq!(crate::parse(".|\n.x").unwrap_err().to_string(), "Invalid tile \"x\" at line 2, column 2");
+    }
 }
\ No newline at end of file
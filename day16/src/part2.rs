@@ -1,12 +1,11 @@
 use std::collections::HashSet;
-use std::time::Instant;
 use glam::IVec2;
 use rayon::prelude::*;
 use aoc2023::direction::Direction;
-use aoc2023::world::World;
-use day16::walk;
+use aoc2023::map2d::Map2D;
+use crate::{walk, Tile};
 
-fn generate_start_positions(world: &World) -> Vec<(IVec2, Direction)> {
+fn generate_start_positions(world: &Map2D<Tile>) -> Vec<(IVec2, Direction)> {
     let mut edge_positions = HashSet::new();
     for x in 0..world.width {
         edge_positions.insert((x, 0));
@@ -39,23 +38,12 @@ fn generate_start_positions(world: &World) -> Vec<(IVec2, Direction)> {
         .collect()
 }
 
-fn main() {
-    let input = include_str!("../input1.txt");
+pub fn solve(world: &Map2D<Tile>) -> usize {
+    let start_positions = generate_start_positions(world);
 
-    let start = Instant::now();
-    let world = World::new(input);
-    let start_positions = generate_start_positions(&world);
-    let parse_duration = start.elapsed();
-
-    let start = Instant::now();
-    let result = start_positions.par_iter()
-        .map(|(pos, dir)| walk(&world, *pos, *dir).len())
-        .max().unwrap();
-    let duration = start.elapsed();
-
-    println!("Result: {}", result);
-    println!("Parsing took: {:?}", parse_duration);
-    println!("Solving took: {:?}", duration);
+    start_positions.par_iter()
+        .map(|(pos, dir)| walk(world, *pos, *dir).len())
+        .max().unwrap()
 }
 
 #[cfg(test)]
@@ -77,7 +65,7 @@ mod test {
 .|....-|.\
 ..//.|....";
 
-        let world = World::new(input);
+        let world = crate::parse(input).unwrap();
         let start_positions = generate_start_positions(&world);
         let result = start_positions.iter()
             .map(|(pos, dir)| walk(&world, *pos, *dir).len())
@@ -91,7 +79,7 @@ mod test {
         let input = r"..
 ..";
 
-        let world = World::new(input);
+        let world = crate::parse(input).unwrap();
         assert_eq!(world.width, 2);
         assert_eq!(world.height, 2);
 
@@ -0,0 +1,57 @@
+//! Compares the heap and bucket queue searches on large generated cities, one search at a time
+//! and with many start/goal pairs searched sequentially or in parallel.
+//!
+//! `cargo run --release -p day17 --example frontiers -- [size] [pairs]`
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use glam::IVec2;
+use aoc2023::gen::{self, Rng};
+use day17::{factory, parse, min_loss_path_with, par_min_losses, CrucibleRules, Frontier};
+
+const SAMPLES: usize = 3;
+
+/// Fastest of a few runs
+fn time<T>(mut f: impl FnMut() -> T) -> Duration {
+    (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let mut args = std::env::args().skip(1).map(|arg| arg.parse::<usize>().expect("Expected a number"));
+    let size = args.next().unwrap_or(150);
+    let n_pairs = args.next().unwrap_or(8);
+
+    let seed = 0x2023_1217;
+    let world = parse(&gen::heat_map(seed, size, size).input).unwrap();
+    let mut rng = Rng::new(seed);
+    let pairs = (0..n_pairs)
+        .map(|_| {
+            let mut pos = || IVec2::new(rng.below(size as u64) as i32, rng.below(size as u64) as i32);
+            (pos(), pos())
+        })
+        .collect::<Vec<_>>();
+
+    println!("{}x{} city, {} start/goal pairs, fastest of {} runs", size, size, n_pairs, SAMPLES);
+    println!("{:<8} {:<10} {:>12} {:>12} {:>12}", "rules", "frontier", "corner", "sequential", "parallel");
+
+    for (name, rules) in [("normal", CrucibleRules::NORMAL), ("ultra", CrucibleRules::ULTRA)] {
+        for frontier in [Frontier::Heap, Frontier::Buckets] {
+            let corner = time(|| min_loss_path_with(&world, rules, IVec2::ZERO, factory(&world), frontier));
+            let sequential = time(|| {
+                pairs.iter()
+                    .map(|&(start, goal)| min_loss_path_with(&world, rules, start, goal, frontier).map(|path| path.cost))
+                    .collect::<Vec<_>>()
+            });
+            let parallel = time(|| par_min_losses(&world, rules, &pairs, frontier));
+
+            println!("{:<8} {:<10} {:>12.2?} {:>12.2?} {:>12.2?}", name, format!("{:?}", frontier), corner, sequential, parallel);
+        }
+    }
+}
@@ -0,0 +1,246 @@
+use glam::IVec2;
+use rayon::prelude::*;
+use aoc2023::direction::Direction;
+use aoc2023::error::ParseError;
+use aoc2023::map2d::Map2D;
+use aoc2023::search::{self, Path};
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Parsed<'a> = Map2D<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(world: &Self::Parsed<'_>) -> miette::Result<u32> {
+        part1::solve(world)
+    }
+
+    fn part2(world: &Self::Parsed<'_>) -> miette::Result<u32> {
+        part2::solve(world)
+    }
+}
+
+/// Parses the heat loss of every block, there has to be at least one
+pub fn parse(input: &str) -> Result<Map2D<u32>, ParseError> {
+    let world = Map2D::parse(input, |c| c.to_digit(10))?;
+    if world.width == 0 || world.height == 0 {
+        return Err(ParseError::expected_at_end("a block", input));
+    }
+    Ok(world)
+}
+
+/// How far a crucible can go in a straight line, and whether it can turn back the way it came
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CrucibleRules {
+    /// Blocks it has to move before it can turn or stop
+    pub min_run: u32,
+    /// Blocks it can move before it has to turn
+    pub max_run: u32,
+    pub can_reverse: bool,
+}
+
+impl CrucibleRules {
+    pub const NORMAL: CrucibleRules = CrucibleRules {
+        min_run: 0,
+        max_run: 3,
+        can_reverse: false,
+    };
+
+    pub const ULTRA: CrucibleRules = CrucibleRules {
+        min_run: 4,
+        max_run: 10,
+        can_reverse: false,
+    };
+}
+
+/// A crucible on its way through the city, `run` counts the blocks it moved since it last turned
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct Crucible {
+    pub pos: IVec2,
+    pub direction: Direction,
+    pub run: u32,
+}
+
+impl Crucible {
+    /// The moves a crucible can make: straight on while it's below `max_run`,
+    /// or a turn once it's done at least `min_run`
+    fn moves(&self, rules: CrucibleRules) -> impl Iterator<Item=Crucible> + '_ {
+        let straight = (self.run < rules.max_run).then_some((self.direction, self.run + 1));
+        let turns = Direction::all().into_iter()
+            .filter(move |&d| self.run >= rules.min_run && d != self.direction)
+            .filter(move |&d| rules.can_reverse || d != self.direction.opposite())
+            .map(|d| (d, 1));
+
+        straight.into_iter().chain(turns)
+            .map(move |(direction, run)| Crucible {
+                pos: self.pos + direction.as_delta(),
+                direction,
+                run,
+            })
+    }
+}
+
+/// The queue ordering the crucibles still to be moved
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Frontier {
+    /// [`search::dijkstra`] with a binary heap
+    Heap,
+    /// [`search::dijkstra_buckets`], heat losses are single digits so the buckets stay dense
+    Buckets,
+}
+
+/// Cheapest path from `start` to `goal` following `rules`, the crucible can set off in any direction.
+/// Returns `None` if the start is off the map or the goal can't be reached.
+pub fn min_loss_path(world: &Map2D<u32>, rules: CrucibleRules, start: IVec2, goal: IVec2) -> Option<Path<Crucible, u32>> {
+    min_loss_path_with(world, rules, start, goal, Frontier::Buckets)
+}
+
+/// Like [`min_loss_path`], searching with the given `frontier`
+pub fn min_loss_path_with(world: &Map2D<u32>, rules: CrucibleRules, start: IVec2, goal: IVec2, frontier: Frontier) -> Option<Path<Crucible, u32>> {
+    if !world.contains(start) {
+        return None;
+    }
+
+    let starts = Direction::all()
+        .map(|direction| Crucible { pos: start, direction, run: 0 });
+    let successors = |crucible: &Crucible| crucible.moves(rules)
+        .filter_map(|next| world.get_v(next.pos).map(|loss| (next, loss)))
+        .collect::<Vec<_>>();
+    let is_goal = |crucible: &Crucible| crucible.pos == goal && crucible.run >= rules.min_run;
+
+    match frontier {
+        Frontier::Heap => search::dijkstra(starts, successors, is_goal),
+        Frontier::Buckets => search::dijkstra_buckets(starts, successors, is_goal),
+    }
+}
+
+/// The least heat loss for every `(start, goal)` pair, searched in parallel
+pub fn par_min_losses(world: &Map2D<u32>, rules: CrucibleRules, pairs: &[(IVec2, IVec2)], frontier: Frontier) -> Vec<Option<u32>> {
+    pairs.par_iter()
+        .map(|&(start, goal)| min_loss_path_with(world, rules, start, goal, frontier).map(|path| path.cost))
+        .collect()
+}
+
+/// The bottom right block, where the crucibles have to be delivered
+pub fn factory(world: &Map2D<u32>) -> IVec2 {
+    IVec2::new(world.width as i32 - 1, world.height as i32 - 1)
+}
+
+/// Draws the heat loss of every block, with the blocks the crucible moves into replaced by an arrow
+/// for the direction it entered them in
+pub fn render_path(world: &Map2D<u32>, path: &Path<Crucible, u32>) -> String {
+    let mut tiles = Map2D::from_vec(world.iter().map(|(_, loss)| loss.to_string()).collect(), world.width);
+    for crucible in path.states.iter().skip(1) {
+        tiles.set_v(crucible.pos, crucible.direction.arrow().to_string());
+    }
+
+    format!("{:?}", tiles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+    const INPUT: &str = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+    #[test]
+    fn test_min_loss_path() {
+        let world = parse(INPUT).unwrap();
+        let path = min_loss_path(&world, CrucibleRules::ULTRA, IVec2::ZERO, factory(&world)).unwrap();
+
+        assert_eq!(path.cost, 71);
+        assert_eq!(path.states.first().map(|c| c.pos), Some(IVec2::ZERO));
+        assert_eq!(path.states.last().map(|c| (c.pos, c.direction, c.run)), Some((IVec2::new(11, 4), Direction::East, 4)));
+        assert_eq!(path.states.len(), 16);
+    }
+
+    #[test]
+    fn test_start_and_goal() {
+        let world = parse(EXAMPLE).unwrap();
+        let corner = factory(&world);
+
+        assert_eq!(min_loss_path(&world, CrucibleRules::NORMAL, IVec2::ZERO, corner).map(|p| p.cost), Some(102));
+        assert_eq!(min_loss_path(&world, CrucibleRules::ULTRA, IVec2::ZERO, corner).map(|p| p.cost), Some(94));
+
+        // Reversed routes lose the heat of the top left block instead of the bottom right one
+        assert_eq!(min_loss_path(&world, CrucibleRules::NORMAL, corner, IVec2::ZERO).map(|p| p.cost), Some(101));
+        assert_eq!(min_loss_path(&world, CrucibleRules::ULTRA, corner, IVec2::ZERO).map(|p| p.cost), Some(93));
+
+        assert_eq!(min_loss_path(&world, CrucibleRules::NORMAL, IVec2::new(-1, 0), corner), None);
+    }
+
+    #[test]
+    fn test_frontiers_agree() {
+        let world = parse(EXAMPLE).unwrap();
+        let corners = [IVec2::ZERO, IVec2::new(12, 0), IVec2::new(0, 12), factory(&world)];
+        let pairs = corners.iter()
+            .flat_map(|&start| corners.map(|goal| (start, goal)))
+            .collect::<Vec<_>>();
+
+        for rules in [CrucibleRules::NORMAL, CrucibleRules::ULTRA] {
+            let heap = par_min_losses(&world, rules, &pairs, Frontier::Heap);
+            let buckets = par_min_losses(&world, rules, &pairs, Frontier::Buckets);
+            assert_eq!(heap, buckets);
+
+            let sequential = pairs.iter()
+                .map(|&(start, goal)| min_loss_path(&world, rules, start, goal).map(|path| path.cost))
+                .collect::<Vec<_>>();
+            assert_eq!(heap, sequential);
+        }
+    }
+
+    #[test]
+    fn test_can_reverse() {
+        // The goal is too close to stop on without overshooting and coming back
+        let world = parse("1111").unwrap();
+        let rules = CrucibleRules { min_run: 2, max_run: 3, can_reverse: false };
+        let goal = IVec2::new(1, 0);
+
+        assert_eq!(min_loss_path(&world, rules, IVec2::ZERO, goal), None);
+
+        let path = min_loss_path(&world, CrucibleRules { can_reverse: true, ..rules }, IVec2::ZERO, goal).unwrap();
+        assert_eq!(path.cost, 5);
+        assert_eq!(path.states.iter().map(|c| c.pos.x).collect::<Vec<_>>(), [0, 1, 2, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_render_path() {
+        let world = parse(INPUT).unwrap();
+        let path = min_loss_path(&world, CrucibleRules::ULTRA, IVec2::ZERO, factory(&world)).unwrap();
+
+        assert_eq!(render_path(&world, &path), "\
+1>>>>>>>1111
+9999999v9991
+9999999v9991
+9999999v9991
+9999999v>>>>
+");
+    }
+}
@@ -0,0 +1,64 @@
+use glam::IVec2;
+use miette::miette;
+use aoc2023::map2d::Map2D;
+use aoc2023::search::Path;
+use crate::{factory, min_loss_path, Crucible, CrucibleRules};
+
+/// Cheapest route for an ultra crucible, which has to move at least 4 and at most 10 blocks before turning
+pub fn route(world: &Map2D<u32>) -> Option<Path<Crucible, u32>> {
+    min_loss_path(world, CrucibleRules::ULTRA, IVec2::ZERO, factory(world))
+}
+
+fn find_min_loss(world: &Map2D<u32>) -> Option<u32> {
+    route(world).map(|path| path.cost)
+}
+
+pub fn solve(world: &Map2D<u32>) -> miette::Result<u32> {
+    find_min_loss(world).ok_or_else(|| miette!("The ultra crucible can't reach the factory"))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533";
+
+        let world = crate::parse(input).unwrap();
+        let result = find_min_loss(&world);
+        assert_eq!(result, Some(94));
+    }
+
+    #[test]
+    fn test_input_2() {
+        let input = "111111111111
+999999999991
+999999999991
+999999999991
+999999999991";
+
+        let world = crate::parse(input).unwrap();
+        let result = find_min_loss(&world);
+        assert_eq!(result, Some(71));
+    }
+
+    #[test]
+    fn test_unreachable() {
+        // Too small to move 4 blocks in a straight line
+        let err = solve(&crate::parse("12\n34").unwrap()).unwrap_err();
+        assert_eq!(err.to_string(), "The ultra crucible can't reach the factory");
+    }
+}
\ No newline at end of file
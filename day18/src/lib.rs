@@ -0,0 +1,66 @@
+use itertools::Itertools;
+use aoc2023::direction::Direction;
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Parsed<'a> = Vec<Command>;
+    type Answer1 = i32;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(commands: &Self::Parsed<'_>) -> miette::Result<i32> {
+        Ok(part1::solve(commands))
+    }
+
+    fn part2(commands: &Self::Parsed<'_>) -> miette::Result<i64> {
+        Ok(part2::solve(commands))
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub struct Command {
+    pub direction: Direction,
+    pub n_steps: u32,
+    pub color: [u8; 3],
+}
+
+fn parse_command(line: &str) -> Result<Command, ParseError> {
+    let (direction, n_steps, color) = line.split_whitespace().collect_tuple()
+        .ok_or_else(|| ParseError::expected("a direction, a step count and a color", line, line))?;
+
+    let direction = Direction::from_udlr(direction)
+        .ok_or_else(|| ParseError::invalid("direction", line, direction))?;
+    let n_steps = parse::number(line, n_steps)?;
+
+    let hex_color = parse::strip_prefix(line, color, "(#")?;
+    let hex_color = hex_color.strip_suffix(')')
+        .ok_or_else(|| ParseError::expected("\")\"", line, &hex_color[hex_color.len()..]))?;
+    let color: [u8; 3] = hex::decode(hex_color).ok()
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or_else(|| ParseError::invalid("color", line, hex_color))?;
+
+    // The last hex digit encodes the direction of the real instruction, see part 2
+    if color[2] & 0xF > 3 {
+        return Err(ParseError::invalid("color", line, hex_color));
+    }
+
+    Ok(Command {
+        direction,
+        n_steps,
+        color,
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Command>, ParseError> {
+    parse::lines(input, parse_command)
+}
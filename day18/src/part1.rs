@@ -1,13 +1,10 @@
-#![feature(let_chains)]
-
 use std::collections::VecDeque;
 use std::fmt::{Debug, Display, Formatter};
 use glam::IVec2;
-use regex::Regex;
 use rustc_hash::FxHashSet;
 use aoc2023::direction::Direction;
 use aoc2023::map2d::Map2D;
-
+use crate::Command;
 
 #[derive(Copy, Clone, Eq, PartialEq)]
 enum Block {
@@ -18,8 +15,8 @@ enum Block {
 impl Debug for Block {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
-            Block::Ground => write!(f, "{}", '.'),
-            Block::Hole(_) => write!(f, "{}", '#')
+            Block::Ground => write!(f, "."),
+            Block::Hole(_) => write!(f, "#")
         }
     }
 }
@@ -30,34 +27,7 @@ impl Display for Block {
     }
 }
 
-struct Command {
-    direction: Direction,
-    n_steps: u32,
-    color: [u8; 3],
-}
-
-fn parse(input: &str) -> Vec<Command> {
-    let re = Regex::new(r"^(?<direction>\w) (?<steps>\d+) \(#(?<color>\w+)\)$").unwrap();
-
-    input.lines()
-        .map(|line| {
-            let caps = re.captures(line).unwrap();
-            let direction = Direction::from_udlr(caps.name("direction").unwrap().as_str());
-            let n_steps = caps.name("steps").unwrap().as_str()
-                .parse().unwrap();
-            let color = hex::decode(caps.name("color").unwrap().as_str()).unwrap()
-                .try_into().unwrap();
-
-            Command {
-                direction,
-                n_steps,
-                color,
-            }
-        })
-        .collect()
-}
-
-fn determine_bounds(commands: &Vec<Command>) -> (IVec2, IVec2) {
+fn determine_bounds(commands: &[Command]) -> (IVec2, IVec2) {
     let mut pos = IVec2::new(0, 0);
     let mut max_x = 0;
     let mut max_y = 0;
@@ -72,10 +42,10 @@ fn determine_bounds(commands: &Vec<Command>) -> (IVec2, IVec2) {
         min_y = min_y.min(pos.y);
     }
 
-    return (IVec2::new(min_x, min_y), IVec2::new(max_x, max_y))
+    (IVec2::new(min_x, min_y), IVec2::new(max_x, max_y))
 }
 
-fn dig(commands: &Vec<Command>) -> Map2D<Block> {
+fn dig(commands: &[Command]) -> Map2D<Block> {
     let (b_min, b_max) = determine_bounds(commands);
 
     let width = b_min.x.abs_diff(b_max.x) as usize + 1;
@@ -106,7 +76,7 @@ fn get_edge_ground(world: &Map2D<Block>) -> FxHashSet<IVec2> {
         Block::Hole(_) => None
     }));
 
-    goals.extend(world.iter_row(world.width - 1).enumerate().filter_map(|(idx, &x)| match x {
+    goals.extend(world.iter_row(world.height - 1).enumerate().filter_map(|(idx, &x)| match x {
         Block::Ground => Some(IVec2::new(idx as i32, world.height as i32 - 1)),
         Block::Hole(_) => None
     }));
@@ -143,6 +113,7 @@ fn fill(world: &mut Map2D<Block>) {
             let mut to_visit = VecDeque::new();
 
             to_visit.push_back(IVec2::new(x as i32, y as i32));
+            visited.insert(IVec2::new(x as i32, y as i32));
 
             while let Some(pos) = to_visit.pop_front() {
                 for direction in Direction::all() {
@@ -152,7 +123,7 @@ fn fill(world: &mut Map2D<Block>) {
                         continue
                     }
 
-                    if let Some(block) = world.get_v(next_pos) && block == Block::Ground {
+                    if world.get_v(next_pos) == Some(Block::Ground) {
                         to_visit.push_back(next_pos);
                         visited.insert(next_pos);
                     }
@@ -185,23 +156,17 @@ fn count(world: &Map2D<Block>) -> i32 {
     count
 }
 
-fn solve(input: &str) -> i32 {
-    let commands = parse(input);
-    let mut world = dig(&commands);
-    println!("World: \n{:?}", world);
+pub fn solve(commands: &[Command]) -> i32 {
+    let mut world = dig(commands);
     fill(&mut world);
-    println!("Filled: \n{:?}", world);
     count(&world)
 }
 
-fn main() {
-    let input = include_str!("../input.txt");
-    let result = solve(input);
-    println!("Result: {}", result);
-}
-
 #[cfg(test)]
 mod test {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use crate::Day18;
     use super::*;
 
     #[test]
@@ -221,7 +186,7 @@ U 3 (#a77fa3)
 L 2 (#015232)
 U 2 (#7a21e3)";
 
-        let commands = parse(input);
+        let commands = crate::parse(input).unwrap();
         let mut world = dig(&commands);
         println!("World: \n{:?}", world);
         fill(&mut world);
@@ -230,4 +195,21 @@ U 2 (#7a21e3)";
 
         assert_eq!(result, 62);
     }
+
+    #[test]
+    fn test_invalid_direction() {
+        let input = "R 6 (#70c710)
+X 5 (#0dc571)";
+
+        let err = crate::parse(input).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid direction \"X\" at line 2, column 1");
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let puzzle = gen::dig_plan(seed, 8);
+            assert_eq!(Day18::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+        }
+    }
 }
@@ -0,0 +1,251 @@
+use glam::IVec2;
+use aoc2023::direction::Direction;
+use crate::Command;
+
+#[derive(Copy, Clone)]
+struct Instruction {
+    direction: Direction,
+    n_steps: i32,
+}
+
+#[cfg(test)]
+fn plain_instructions(commands: &[Command]) -> Vec<Instruction> {
+    commands.iter()
+        .map(|command| Instruction {
+            direction: command.direction,
+            n_steps: command.n_steps as i32,
+        })
+        .collect()
+}
+
+// The real instructions are hidden in the color: five hex digits of distance, then one of direction
+fn decode_instructions(commands: &[Command]) -> Vec<Instruction> {
+    commands.iter()
+        .map(|command| {
+            let [r, g, b] = command.color;
+            let value = u32::from_be_bytes([0, r, g, b]);
+
+            // Only 0-3 make it through parsing
+            let direction = match value & 0xF {
+                0 => Direction::East,
+                1 => Direction::South,
+                2 => Direction::West,
+                _ => Direction::North,
+            };
+
+            Instruction {
+                direction,
+                n_steps: (value >> 4) as i32,
+            }
+        })
+        .collect()
+}
+
+fn determine_bounds(instructions: &[Instruction]) -> (IVec2, IVec2) {
+    let mut pos = IVec2::new(0, 0);
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut min_x = 0;
+    let mut min_y = 0;
+
+    for Instruction { direction, n_steps, .. } in instructions {
+        pos += direction.as_delta() * *n_steps;
+        max_x = max_x.max(pos.x);
+        max_y = max_y.max(pos.y);
+        min_x = min_x.min(pos.x);
+        min_y = min_y.min(pos.y);
+    }
+
+    (IVec2::new(min_x, min_y), IVec2::new(max_x, max_y))
+}
+
+struct Segment(IVec2, IVec2, Direction);
+
+impl Segment {
+    fn length(&self) -> IVec2 {
+        (self.0 - self.1).abs()
+    }
+}
+
+fn get_segments(instructions: &[Instruction]) -> Vec<Segment> {
+    let (b_min, _) = determine_bounds(instructions);
+    let mut segments = Vec::new();
+
+    let mut pos = b_min.abs();
+    for Instruction { direction, n_steps, .. } in instructions {
+        let new_pos = pos + direction.as_delta() * *n_steps;
+        segments.push(Segment(pos, new_pos, *direction));
+        pos = new_pos;
+    }
+    segments
+}
+
+/// Twice the signed area of the trench outline, positive when it's dug clockwise
+fn winding(instructions: &[Instruction]) -> i64 {
+    let mut pos = IVec2::ZERO;
+    let mut total = 0;
+
+    for Instruction { direction, n_steps } in instructions {
+        let next = pos + direction.as_delta() * *n_steps;
+        total += pos.x as i64 * next.y as i64 - next.x as i64 * pos.y as i64;
+        pos = next;
+    }
+    total
+}
+
+fn calc_area(instructions: &[Instruction]) -> i64 {
+    // The sweep below relies on the trench being dug clockwise
+    if winding(instructions) < 0 {
+        let reversed = instructions.iter().rev()
+            .map(|instruction| Instruction {
+                direction: instruction.direction.opposite(),
+                ..*instruction
+            })
+            .collect::<Vec<_>>();
+        return calc_area(&reversed);
+    }
+
+    let segments = get_segments(instructions);
+
+    let mut total: i64 = 0;
+
+    for (idx, segment) in segments.iter().enumerate() {
+        let prev_idx = match idx {
+            0 => segments.len() - 1,
+            x => x - 1
+        };
+        let next_idx = (idx + 1) % segments.len();
+
+        let prev_dir = segments.get(prev_idx).unwrap().2;
+        let next_dir = segments.get(next_idx).unwrap().2;
+
+        match segment.2 {
+            Direction::North => {
+                let mut edges = 0;
+                if prev_dir == Direction::East {
+                    edges += 1;
+                }
+                if next_dir == Direction::West {
+                    edges += 1;
+                }
+
+                let segment_length = segment.length().y + 1;
+
+                let to_sub = (segment_length - edges) as i64 * segment.0.x as i64;
+
+                total -= to_sub
+            }
+            Direction::South => {
+                let mut edges = 0;
+                if prev_dir == Direction::West {
+                    edges += 1;
+                }
+                if next_dir == Direction::East {
+                    edges += 1;
+                }
+
+                let segment_length = segment.length().y + 1;
+
+                let to_add = (segment_length - edges) as i64 * (segment.0.x + 1) as i64;
+
+                total += to_add;
+            }
+
+            _ => {}
+        }
+    }
+
+    total
+}
+
+pub fn solve(commands: &[Command]) -> i64 {
+    calc_area(&decode_instructions(commands))
+}
+
+#[cfg(test)]
+mod test {
+    use proptest::prelude::*;
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use crate::{part1, Day18};
+    use super::*;
+
+    /// The same trench dug the other way round
+    fn reversed(commands: &[Command]) -> Vec<Command> {
+        commands.iter().rev()
+            .map(|command| Command {
+                direction: command.direction.opposite(),
+                ..*command
+            })
+            .collect()
+    }
+
+    proptest! {
+        #[test]
+        fn prop_area_matches_flood_fill(seed in any::<u64>(), size in 1..7usize) {
+            let commands = crate::parse(&gen::dig_plan(seed, size).input).unwrap();
+            prop_assert_eq!(calc_area(&plain_instructions(&commands)), part1::solve(&commands) as i64);
+
+            let commands = reversed(&commands);
+            prop_assert_eq!(calc_area(&plain_instructions(&commands)), part1::solve(&commands) as i64);
+        }
+    }
+
+    #[test]
+    fn test_input_1() {
+        let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let commands = crate::parse(input).unwrap();
+        let result = calc_area(&plain_instructions(&commands));
+        assert_eq!(result, 62);
+    }
+
+    #[test]
+    fn test_input_1_pt2() {
+        let input = "R 6 (#70c710)
+D 5 (#0dc571)
+L 2 (#5713f0)
+D 2 (#d2c081)
+R 2 (#59c680)
+D 2 (#411b91)
+L 5 (#8ceee2)
+U 2 (#caa173)
+L 1 (#1b58a2)
+U 2 (#caa171)
+R 2 (#7807d2)
+U 3 (#a77fa3)
+L 2 (#015232)
+U 2 (#7a21e3)";
+        let commands = crate::parse(input).unwrap();
+        let result = calc_area(&decode_instructions(&commands));
+        assert_eq!(result, 952408144115);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = &aoc2023::input::load(18).unwrap();
+        let commands = crate::parse(input).unwrap();
+        let result = calc_area(&plain_instructions(&commands));
+        assert_eq!(result, 35244)
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let puzzle = gen::dig_plan(seed, 8);
+            assert_eq!(Day18::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
+}
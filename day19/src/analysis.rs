@@ -0,0 +1,261 @@
+use std::fmt;
+use rustc_hash::FxHashSet;
+use aoc2023::search;
+use crate::*;
+
+/// Something off about a set of workflows, found without running any parts through them
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Diagnostic<'a> {
+    /// There's no `in` workflow to start from
+    MissingStart,
+    /// A rule sends parts to a workflow that isn't defined
+    UndefinedWorkflow { workflow: &'a str, rule: usize, target: &'a str },
+    /// A rule compares a category the domain doesn't have
+    UnknownCategory { workflow: &'a str, rule: usize, category: &'a str },
+    /// Workflows that can send parts round forever, each one leads to the next and the last back to the first
+    Cycle(Vec<&'a str>),
+    /// No chain of rules leads from `in` to the workflow
+    Unreachable(&'a str),
+    /// Every part meeting the rule's condition is taken by an earlier rule, or there are none in the domain
+    DeadRule { workflow: &'a str, rule: usize },
+    /// Every part sent to the workflow gets the same verdict, so it could be replaced by that verdict
+    ConstantVerdict { workflow: &'a str, verdict: Target<'a> },
+}
+
+impl fmt::Display for Diagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Diagnostic::MissingStart => write!(f, "there's no workflow named in"),
+            Diagnostic::UndefinedWorkflow { workflow, rule, target } =>
+                write!(f, "{}[{}] sends parts to {}, which isn't defined", workflow, rule, target),
+            Diagnostic::UnknownCategory { workflow, rule, category } =>
+                write!(f, "{}[{}] compares {}, which isn't in the domain", workflow, rule, category),
+            Diagnostic::Cycle(cycle) => write!(f, "{} -> {} is a cycle", cycle.join(" -> "), cycle[0]),
+            Diagnostic::Unreachable(workflow) => write!(f, "{} can't be reached from in", workflow),
+            Diagnostic::DeadRule { workflow, rule } => write!(f, "{}[{}] never applies", workflow, rule),
+            Diagnostic::ConstantVerdict { workflow, verdict } => write!(f, "{} always ends in {}", workflow, verdict),
+        }
+    }
+}
+
+/// Checks the workflows for parts rated within `domain`, grouping the diagnostics by kind and ordering them by workflow
+pub fn analyze<'a>(workflows: &WorkflowMap<'a>, domain: &Domain) -> Vec<Diagnostic<'a>> {
+    let mut names = workflows.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut diagnostics = Vec::new();
+
+    if !workflows.contains_key("in") {
+        diagnostics.push(Diagnostic::MissingStart);
+    }
+
+    for &name in &names {
+        for (rule, Rule { target, .. }) in workflows[name].rules.iter().enumerate() {
+            if let Target::Workflow(target) = *target {
+                if !workflows.contains_key(target) {
+                    diagnostics.push(Diagnostic::UndefinedWorkflow { workflow: name, rule, target });
+                }
+            }
+        }
+    }
+
+    for &name in &names {
+        for (rule, condition) in workflows[name].rules.iter().enumerate().filter_map(|(idx, rule)| Some((idx, rule.condition?))) {
+            if domain.index(condition.category).is_none() {
+                diagnostics.push(Diagnostic::UnknownCategory { workflow: name, rule, category: condition.category });
+            }
+        }
+    }
+
+    diagnostics.extend(cycles(workflows, &names).into_iter().map(Diagnostic::Cycle));
+
+    let start = workflows.contains_key("in").then_some("in");
+    let reachable = search::bfs_distances(start, |&name| successors(workflows, name));
+    diagnostics.extend(names.iter()
+        .filter(|name| !reachable.contains_key(*name))
+        .map(|name| Diagnostic::Unreachable(name)));
+
+    for &name in &names {
+        diagnostics.extend(dead_rules(&workflows[name], domain).into_iter()
+            .map(|rule| Diagnostic::DeadRule { workflow: name, rule }));
+    }
+
+    for &name in &names {
+        if let Some(verdict) = constant_verdict(workflows, domain, name) {
+            diagnostics.push(Diagnostic::ConstantVerdict { workflow: name, verdict });
+        }
+    }
+
+    diagnostics
+}
+
+/// The defined workflows a workflow sends parts to, each only once
+fn successors<'a>(workflows: &WorkflowMap<'a>, name: &str) -> Vec<&'a str> {
+    let mut successors = Vec::new();
+    for rule in &workflows[name].rules {
+        if let Target::Workflow(target) = rule.target {
+            if workflows.contains_key(target) && !successors.contains(&target) {
+                successors.push(target);
+            }
+        }
+    }
+    successors
+}
+
+/// Every cycle closed by an edge back into the depth first search stack, starting from `in`
+fn cycles<'a>(workflows: &WorkflowMap<'a>, names: &[&'a str]) -> Vec<Vec<&'a str>> {
+    fn visit<'a>(
+        workflows: &WorkflowMap<'a>,
+        name: &'a str,
+        stack: &mut Vec<&'a str>,
+        done: &mut FxHashSet<&'a str>,
+        cycles: &mut Vec<Vec<&'a str>>,
+    ) {
+        stack.push(name);
+        for next in successors(workflows, name) {
+            if let Some(pos) = stack.iter().position(|&name| name == next) {
+                cycles.push(stack[pos..].to_vec());
+            } else if !done.contains(next) {
+                visit(workflows, next, stack, done, cycles);
+            }
+        }
+        stack.pop();
+        done.insert(name);
+    }
+
+    let mut done = FxHashSet::default();
+    let mut cycles = Vec::new();
+
+    let start = workflows.contains_key("in").then_some("in");
+    for &name in start.iter().chain(names) {
+        if !done.contains(name) {
+            visit(workflows, name, &mut Vec::new(), &mut done, &mut cycles);
+        }
+    }
+
+    cycles
+}
+
+/// Indices of the rules no part in `domain` reaches with its condition met
+fn dead_rules(workflow: &Workflow, domain: &Domain) -> Vec<usize> {
+    let mut remaining = vec![domain.cuboid()];
+    let mut dead = Vec::new();
+
+    for (idx, rule) in workflow.rules.iter().enumerate() {
+        if remaining.is_empty() {
+            dead.push(idx);
+            continue;
+        }
+
+        let Some(condition) = rule.condition else {
+            remaining.clear();
+            continue;
+        };
+        // Reported as an unknown category, all that's known is that some parts might get past it
+        let Some(category) = domain.index(condition.category) else {
+            continue;
+        };
+
+        let mut matched = false;
+        let mut rest = Vec::new();
+        for cuboid in remaining {
+            let (inside, outside) = cuboid.split(category, &condition.matching());
+            matched |= inside.is_some();
+            rest.extend(outside);
+        }
+
+        if !matched {
+            dead.push(idx);
+        }
+        remaining = rest;
+    }
+
+    dead
+}
+
+/// The one verdict every part in `domain` sent to `name` gets, if there's only one.
+/// Workflows leading into a cycle or an undefined workflow are never constant, as some parts get no verdict.
+pub(crate) fn constant_verdict<'a>(workflows: &WorkflowMap<'a>, domain: &Domain, name: &'a str) -> Option<Target<'a>> {
+    let mut verdicts = Vec::new();
+    sort_boxes(workflows, domain, Target::Workflow(name), domain.cuboid(), &mut Vec::new(), &mut |verdict, _| {
+        if !verdicts.contains(&verdict) {
+            verdicts.push(verdict);
+        }
+    }).ok()?;
+
+    match verdicts[..] {
+        [verdict] => Some(verdict),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let (workflows, _) = parse("px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+").unwrap();
+
+        // qs only leads to acceptance through lnx
+        assert_eq!(analyze(&workflows, &Domain::xmas()), [
+            Diagnostic::ConstantVerdict { workflow: "gd", verdict: Target::Reject },
+            Diagnostic::ConstantVerdict { workflow: "lnx", verdict: Target::Accept },
+            Diagnostic::ConstantVerdict { workflow: "qs", verdict: Target::Accept },
+        ]);
+    }
+
+    #[test]
+    fn test_diagnostics() {
+        let (workflows, _) = parse("in{x>10:a,x>20:b,y<5:c,R}
+a{x<5:R,m>2:A,x>=11:d,A}
+b{A}
+c{x<3:c,e}
+d{f}
+e{in}
+lone{R}
+
+").unwrap();
+        let domain = Domain::new([("x", 1..=100), ("y", 1..=100)]);
+        let diagnostics = analyze(&workflows, &domain);
+
+        // Rules are only checked against the earlier rules of their own workflow, so a<5 isn't dead,
+        // and b is still reachable through a dead rule
+        assert_eq!(diagnostics, [
+            Diagnostic::UndefinedWorkflow { workflow: "d", rule: 0, target: "f" },
+            Diagnostic::UnknownCategory { workflow: "a", rule: 1, category: "m" },
+            Diagnostic::Cycle(vec!["c"]),
+            Diagnostic::Cycle(vec!["in", "c", "e"]),
+            Diagnostic::Unreachable("lone"),
+            Diagnostic::DeadRule { workflow: "in", rule: 1 },
+            Diagnostic::ConstantVerdict { workflow: "b", verdict: Target::Accept },
+            Diagnostic::ConstantVerdict { workflow: "lone", verdict: Target::Reject },
+        ]);
+
+        assert_eq!(diagnostics[3].to_string(), "in -> c -> e -> in is a cycle");
+        assert_eq!(diagnostics[5].to_string(), "in[1] never applies");
+    }
+
+    #[test]
+    fn test_dead_rules() {
+        let workflow = Workflow::new("w{x>=4000:A,x==4000:R,x>4000:A,x<=1:R,x<2:A,x>1:R,A}").unwrap();
+        assert_eq!(dead_rules(&workflow, &Domain::xmas()), [1, 2, 4, 6]);
+
+        assert_eq!(analyze(&parse("in{m<1:R,A}\n\n").unwrap().0, &Domain::xmas()), [
+            Diagnostic::DeadRule { workflow: "in", rule: 0 },
+            Diagnostic::ConstantVerdict { workflow: "in", verdict: Target::Accept },
+        ]);
+    }
+}
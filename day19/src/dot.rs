@@ -0,0 +1,54 @@
+use std::fmt::Write;
+use crate::*;
+
+/// The workflow graph in Graphviz DOT, with an edge for every rule labelled by its condition.
+/// Accepted and rejected parts end up in the `A` and `R` nodes.
+pub fn to_dot(workflows: &WorkflowMap) -> String {
+    let mut names = workflows.keys().copied().collect::<Vec<_>>();
+    names.sort_unstable();
+
+    let mut dot = String::new();
+    writeln!(dot, "digraph workflows {{").unwrap();
+    writeln!(dot, "    node [shape=box];").unwrap();
+    writeln!(dot, "    \"A\" [shape=doublecircle, color=green];").unwrap();
+    writeln!(dot, "    \"R\" [shape=doublecircle, color=red];").unwrap();
+    if workflows.contains_key("in") {
+        writeln!(dot, "    \"in\" [style=bold];").unwrap();
+    }
+
+    for name in names {
+        for rule in &workflows[name].rules {
+            write!(dot, "    \"{}\" -> \"{}\"", name, rule.target).unwrap();
+            match rule.condition {
+                Some(condition) => writeln!(dot, " [label=\"{}\"];", condition).unwrap(),
+                None => writeln!(dot, ";").unwrap(),
+            }
+        }
+    }
+
+    dot.push_str("}\n");
+    dot
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_dot() {
+        let (workflows, _) = parse("in{s<1351:px,A}\npx{a>=2006:R,m==7:A,R}\n\n").unwrap();
+
+        assert_eq!(to_dot(&workflows), r#"digraph workflows {
+    node [shape=box];
+    "A" [shape=doublecircle, color=green];
+    "R" [shape=doublecircle, color=red];
+    "in" [style=bold];
+    "in" -> "px" [label="s<1351"];
+    "in" -> "A";
+    "px" -> "R" [label="a>=2006"];
+    "px" -> "A" [label="m==7"];
+    "px" -> "R";
+}
+"#);
+    }
+}
@@ -0,0 +1,737 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::BuildHasherDefault;
+use std::ops::{Range, RangeInclusive};
+use miette::IntoDiagnostic;
+use region::{BoxSet, Cuboid};
+use rustc_hash::{FxHasher, FxHashMap};
+use thiserror::Error;
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod analysis;
+pub mod dot;
+pub mod part1;
+pub mod part2;
+pub mod region;
+pub mod simplify;
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Parsed<'a> = (WorkflowMap<'a>, Vec<Part<'a>>);
+    type Answer1 = Value;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1((workflows, parts): &Self::Parsed<'_>) -> miette::Result<Value> {
+        part1::solve(workflows, parts).into_diagnostic()
+    }
+
+    fn part2((workflows, _parts): &Self::Parsed<'_>) -> miette::Result<u128> {
+        part2::solve(workflows).into_diagnostic()
+    }
+}
+
+pub type Value = i64;
+
+/// Ratings stop one short of `Value::MAX`, which ends the ranges of values matching `>` and `>=`
+const MAX_RATING: Value = Value::MAX - 1;
+
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub enum Operator {
+    GT,
+    LT,
+    GE,
+    LE,
+    EQ,
+}
+
+impl Operator {
+    /// Longest symbols first, so `<=` isn't taken for `<`
+    const SYMBOLS: [(&'static str, Operator); 5] = [
+        ("<=", Operator::LE),
+        (">=", Operator::GE),
+        ("==", Operator::EQ),
+        ("<", Operator::LT),
+        (">", Operator::GT),
+    ];
+
+    pub fn symbol(&self) -> &'static str {
+        Self::SYMBOLS.iter().find(|(_, operator)| operator == self).unwrap().0
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+/// A comparison of one of a part's ratings against a fixed value, like `a<2006`
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Condition<'a> {
+    pub category: &'a str,
+    pub operator: Operator,
+    pub value: Value,
+}
+
+impl<'a> Condition<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let operator_idx = input.find(['<', '>', '='])
+            .ok_or_else(|| ParseError::expected("a comparison", input, input))?;
+
+        let category = &input[..operator_idx];
+        if category.is_empty() || !category.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(ParseError::invalid("category", input, category));
+        }
+
+        let rest = &input[operator_idx..];
+        let (symbol, operator) = Operator::SYMBOLS.into_iter()
+            .find(|(symbol, _)| rest.starts_with(symbol))
+            .ok_or_else(|| ParseError::invalid("operator", input, &rest[..1]))?;
+        let value = parse::number(input, &rest[symbol.len()..])?;
+
+        Ok(Self {
+            category,
+            operator,
+            value,
+        })
+    }
+
+    /// Every value satisfying the condition
+    pub fn matching(&self) -> Range<Value> {
+        match self.operator {
+            Operator::GT => self.value.saturating_add(1)..Value::MAX,
+            Operator::LT => Value::MIN..self.value,
+            Operator::GE => self.value..Value::MAX,
+            Operator::LE => Value::MIN..self.value.saturating_add(1),
+            Operator::EQ => self.value..self.value.saturating_add(1),
+        }
+    }
+
+    pub fn holds(&self, rating: Value) -> bool {
+        self.matching().contains(&rating)
+    }
+}
+
+impl fmt::Display for Condition<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}{}", self.category, self.operator, self.value)
+    }
+}
+
+/// Where a rule sends a part
+#[derive(Eq, PartialEq, Debug, Copy, Clone, Hash)]
+pub enum Target<'a> {
+    Accept,
+    Reject,
+    Workflow(&'a str),
+}
+
+impl<'a> Target<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        match input {
+            "A" => Ok(Target::Accept),
+            "R" => Ok(Target::Reject),
+            name if !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') => Ok(Target::Workflow(name)),
+            _ => Err(ParseError::invalid("target", input, input)),
+        }
+    }
+}
+
+impl fmt::Display for Target<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Accept => f.write_str("A"),
+            Target::Reject => f.write_str("R"),
+            Target::Workflow(name) => f.write_str(name),
+        }
+    }
+}
+
+/// Sends a part to `target` if it meets the condition, rules without one match every part
+#[derive(Eq, PartialEq, Debug, Copy, Clone)]
+pub struct Rule<'a> {
+    pub condition: Option<Condition<'a>>,
+    pub target: Target<'a>,
+}
+
+impl<'a> Rule<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let Some((condition, target)) = input.split_once(':') else {
+            return Ok(Self {
+                condition: None,
+                target: Target::new(input)?,
+            });
+        };
+
+        Ok(Self {
+            condition: Some(Condition::new(condition).map_err(|err| err.within(input, condition))?),
+            target: Target::new(target).map_err(|err| err.within(input, target))?,
+        })
+    }
+
+    /// The rule's target, if it applies to `part`
+    pub fn apply(&self, part: &Part) -> Result<Option<Target<'a>>, EvalError> {
+        let Some(condition) = self.condition else {
+            return Ok(Some(self.target));
+        };
+
+        let rating = part.get(condition.category)
+            .ok_or_else(|| EvalError::MissingRating(condition.category.to_string()))?;
+        Ok(condition.holds(rating).then_some(self.target))
+    }
+}
+
+impl fmt::Display for Rule<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.condition {
+            Some(condition) => write!(f, "{}:{}", condition, self.target),
+            None => write!(f, "{}", self.target),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Workflow<'a> {
+    pub name: &'a str,
+    pub rules: Vec<Rule<'a>>,
+}
+
+impl<'a> Workflow<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let (name, rules) = parse::split_once(input, input, "{")?;
+        // Names have to be usable as targets, so not A or R either
+        if !matches!(Target::new(name), Ok(Target::Workflow(_))) {
+            return Err(ParseError::invalid("workflow name", input, name));
+        }
+        let rules = rules.strip_suffix('}')
+            .ok_or_else(|| ParseError::expected("'}'", input, &rules[rules.len()..]))?;
+
+        let rules = rules.split(',')
+            .map(|rule| Rule::new(rule).map_err(|err| err.within(input, rule)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Every part has to end up somewhere
+        if rules.last().is_none_or(|rule| rule.condition.is_some()) {
+            return Err(ParseError::expected("a final rule without a condition", input, input));
+        }
+
+        Ok(Self {
+            name,
+            rules,
+        })
+    }
+
+    /// The first rule applying to `part`, along with its index
+    pub fn apply(&self, part: &Part) -> Result<(usize, Target<'a>), EvalError> {
+        for (idx, rule) in self.rules.iter().enumerate() {
+            if let Some(target) = rule.apply(part)? {
+                return Ok((idx, target));
+            }
+        }
+        unreachable!("The last rule has no condition");
+    }
+}
+
+impl fmt::Display for Workflow<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{{", self.name)?;
+        for (idx, rule) in self.rules.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}", rule)?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// A part's ratings, in any categories
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Part<'a> {
+    ratings: Vec<(&'a str, Value)>,
+}
+
+impl<'a> Part<'a> {
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let ratings = parse::strip_prefix(input, input, "{")?;
+        let ratings = ratings.strip_suffix('}')
+            .ok_or_else(|| ParseError::expected("'}'", input, &ratings[ratings.len()..]))?;
+
+        let mut part = Self { ratings: Vec::new() };
+        for rating in ratings.split(',') {
+            let (category, value) = parse::split_once(input, rating, "=")?;
+            if part.get(category).is_some() {
+                return Err(ParseError::invalid("repeated category", input, category));
+            }
+            let rating = parse::number(input, value)?;
+            if rating > MAX_RATING {
+                return Err(ParseError::invalid("rating, it's too large", input, value));
+            }
+            part.ratings.push((category, rating));
+        }
+
+        Ok(part)
+    }
+
+    pub fn get(&self, category: &str) -> Option<Value> {
+        self.ratings.iter()
+            .find(|(name, _)| *name == category)
+            .map(|&(_, value)| value)
+    }
+
+    pub fn ratings(&self) -> &[(&'a str, Value)] {
+        &self.ratings
+    }
+
+    /// The sum of the part's ratings, unless it's too large for a `Value`
+    pub fn value(&self) -> Option<Value> {
+        self.ratings.iter().try_fold(0, |sum: Value, &(_, value)| sum.checked_add(value))
+    }
+}
+
+impl fmt::Display for Part<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("{")?;
+        for (idx, (category, value)) in self.ratings.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(",")?;
+            }
+            write!(f, "{}={}", category, value)?;
+        }
+        f.write_str("}")
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum EvalError {
+    #[error("Workflow {0:?} isn't defined")]
+    UndefinedWorkflow(String),
+    #[error("No rating for category {0:?}")]
+    MissingRating(String),
+    #[error("Workflow {0:?} sends parts round in a cycle")]
+    Cycle(String),
+    #[error("The ratings of the accepted parts add up to more than {}", Value::MAX)]
+    Overflow,
+}
+
+/// The values each category can take, when counting every possible part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Domain<'a> {
+    categories: Vec<(&'a str, RangeInclusive<Value>)>,
+}
+
+impl<'a> Domain<'a> {
+    /// Panics if a category's range goes past the largest rating, `Value::MAX - 1`
+    pub fn new(categories: impl IntoIterator<Item=(&'a str, RangeInclusive<Value>)>) -> Self {
+        let categories = categories.into_iter().collect::<Vec<_>>();
+        for (category, range) in &categories {
+            assert!(*range.end() <= MAX_RATING, "Category {:?} goes past the largest rating {}", category, MAX_RATING);
+        }
+        Self {
+            categories,
+        }
+    }
+
+    /// The puzzle's domain, every rating from 1 to 4000 in categories `x`, `m`, `a` and `s`
+    pub fn xmas() -> Domain<'static> {
+        Domain::new(CATEGORIES.map(|category| (category, 1..=4000)))
+    }
+
+    pub fn categories(&self) -> impl Iterator<Item=&'a str> + '_ {
+        self.categories.iter().map(|&(category, _)| category)
+    }
+
+    pub fn index(&self, category: &str) -> Option<usize> {
+        self.categories().position(|name| name == category)
+    }
+
+    /// Every part in the domain, with a dimension for each category in order
+    pub fn cuboid(&self) -> Cuboid {
+        Cuboid::new(self.categories.iter().map(|(_, range)| *range.start()..range.end() + 1))
+    }
+
+    /// The part's ratings in category order, if it has one for every category
+    pub fn point(&self, part: &Part) -> Option<Vec<Value>> {
+        self.categories().map(|category| part.get(category)).collect()
+    }
+
+    /// The box with its categories named, like `{x=1..=1415,m=1..=4000}`
+    pub fn describe(&self, cuboid: &Cuboid) -> String {
+        let ranges = self.categories().zip(&cuboid.ranges)
+            .map(|(category, range)| format!("{}={}..={}", category, range.start, range.end - 1))
+            .collect::<Vec<_>>();
+        format!("{{{}}}", ranges.join(","))
+    }
+}
+
+const CATEGORIES: [&str; 4] = ["x", "m", "a", "s"];
+
+pub type WorkflowMap<'a> = HashMap<&'a str, Workflow<'a>, BuildHasherDefault<FxHasher>>;
+
+/// A workflow a part went through, and the rule that sent it on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Step<'a> {
+    pub workflow: &'a str,
+    /// Index of the first rule that applied
+    pub rule: usize,
+    /// The comparison that decided it along with the part's rating, none for a rule without a condition
+    pub comparison: Option<(Condition<'a>, Value)>,
+    pub target: Target<'a>,
+}
+
+impl fmt::Display for Step<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}[{}]", self.workflow, self.rule)?;
+        if let Some((condition, rating)) = self.comparison {
+            write!(f, " {} ({}={})", condition, condition.category, rating)?;
+        }
+        write!(f, " -> {}", self.target)
+    }
+}
+
+/// How a part was sorted, from `in` to its verdict
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace<'a> {
+    pub steps: Vec<Step<'a>>,
+}
+
+impl<'a> Trace<'a> {
+    /// Either [`Target::Accept`] or [`Target::Reject`]
+    pub fn verdict(&self) -> Target<'a> {
+        self.steps.last().unwrap().target
+    }
+
+    pub fn workflows(&self) -> impl Iterator<Item=&'a str> + '_ {
+        self.steps.iter().map(|step| step.workflow)
+    }
+}
+
+/// Runs `part` through the workflows starting at `in`, recording every step until it's accepted or rejected
+pub fn trace<'a>(workflows: &WorkflowMap<'a>, part: &Part) -> Result<Trace<'a>, EvalError> {
+    let mut target = Target::Workflow("in");
+    let mut steps: Vec<Step> = Vec::new();
+
+    while let Target::Workflow(name) = target {
+        // Workflows only look at the part, so coming back to one means going round forever
+        if steps.iter().any(|step| step.workflow == name) {
+            return Err(EvalError::Cycle(name.to_string()));
+        }
+
+        let workflow = workflows.get(name).ok_or_else(|| EvalError::UndefinedWorkflow(name.to_string()))?;
+        let (rule, next) = workflow.apply(part)?;
+        let comparison = workflow.rules[rule].condition
+            .map(|condition| (condition, part.get(condition.category).unwrap()));
+
+        steps.push(Step {
+            workflow: name,
+            rule,
+            comparison,
+            target: next,
+        });
+        target = next;
+    }
+
+    Ok(Trace { steps })
+}
+
+/// Runs `part` through the workflows starting at `in`, until it's accepted or rejected
+pub fn evaluate<'a>(workflows: &WorkflowMap<'a>, part: &Part) -> Result<Target<'a>, EvalError> {
+    trace(workflows, part).map(|trace| trace.verdict())
+}
+
+/// Every part with ratings in `domain` that ends up accepted
+pub fn accepted_region(workflows: &WorkflowMap, domain: &Domain) -> Result<BoxSet, EvalError> {
+    let mut accepted = Vec::new();
+    sort_boxes(workflows, domain, Target::Workflow("in"), domain.cuboid(), &mut Vec::new(), &mut |verdict, cuboid| {
+        if verdict == Target::Accept {
+            accepted.push(cuboid);
+        }
+    })?;
+
+    // A part only takes one route through the workflows
+    Ok(BoxSet::from_disjoint(accepted))
+}
+
+/// How many parts with ratings in `domain` end up accepted
+pub fn count_accepted(workflows: &WorkflowMap, domain: &Domain) -> Result<u128, EvalError> {
+    accepted_region(workflows, domain).map(|region| region.volume())
+}
+
+/// Sends every part within `cuboid` to `target` at once, calling `verdict` with each box of parts as it's accepted or rejected.
+/// `path` holds the workflows leading here.
+fn sort_boxes<'a>(
+    workflows: &WorkflowMap<'a>,
+    domain: &Domain,
+    target: Target<'a>,
+    cuboid: Cuboid,
+    path: &mut Vec<&'a str>,
+    verdict: &mut impl FnMut(Target<'a>, Cuboid),
+) -> Result<(), EvalError> {
+    let Target::Workflow(name) = target else {
+        verdict(target, cuboid);
+        return Ok(());
+    };
+
+    if path.contains(&name) {
+        return Err(EvalError::Cycle(name.to_string()));
+    }
+    let workflow = workflows.get(name).ok_or_else(|| EvalError::UndefinedWorkflow(name.to_string()))?;
+    path.push(name);
+
+    // Parts not taken by any earlier rule, as boxes of ratings
+    let mut remaining = vec![cuboid];
+
+    for rule in &workflow.rules {
+        let Some(condition) = rule.condition else {
+            for cuboid in remaining.drain(..) {
+                sort_boxes(workflows, domain, rule.target, cuboid, path, verdict)?;
+            }
+            break;
+        };
+
+        let idx = domain.index(condition.category)
+            .ok_or_else(|| EvalError::MissingRating(condition.category.to_string()))?;
+        let matching = condition.matching();
+
+        let mut rest = Vec::new();
+        for cuboid in remaining {
+            let (inside, outside) = cuboid.split(idx, &matching);
+            if let Some(inside) = inside {
+                sort_boxes(workflows, domain, rule.target, inside, path, verdict)?;
+            }
+            rest.extend(outside);
+        }
+        remaining = rest;
+    }
+
+    path.pop();
+    Ok(())
+}
+
+pub fn parse(input: &str) -> Result<(WorkflowMap<'_>, Vec<Part<'_>>), ParseError> {
+    let (input_workflows, input_parts) = input.split_once("\n\n")
+        .ok_or_else(|| ParseError::expected_at_end("workflows and parts separated by an empty line", input))?;
+
+    let workflows = parse::lines(input_workflows, Workflow::new)
+        .map_err(|err| err.within(input, input_workflows))?;
+    let mut workflow_map = FxHashMap::default();
+    for workflow in workflows {
+        if workflow_map.contains_key(workflow.name) {
+            return Err(ParseError::invalid("repeated workflow", input, workflow.name));
+        }
+        workflow_map.insert(workflow.name, workflow);
+    }
+
+    let parts = parse::lines(input_parts, Part::new)
+        .map_err(|err| err.within(input, input_parts))?;
+
+    Ok((workflow_map, parts))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_condition() {
+        for (input, operator) in [("a<2006", Operator::LT), ("a>2006", Operator::GT), ("a<=2006", Operator::LE), ("a>=2006", Operator::GE), ("a==2006", Operator::EQ)] {
+            let condition = Condition::new(input).unwrap();
+            assert_eq!(condition, Condition { category: "a", operator, value: 2006 });
+            assert_eq!(condition.to_string(), input);
+        }
+
+        let condition = Condition::new("shiny>=-5").unwrap();
+        assert_eq!((condition.category, condition.value), ("shiny", -5));
+
+        assert!(Condition::new("<5").is_err());
+        assert!(Condition::new("a=5").is_err());
+        assert!(Condition::new("a<").is_err());
+    }
+
+    #[test]
+    fn test_matching() {
+        let holds = |input: &str| (0..6).filter(|&value| Condition::new(input).unwrap().holds(value)).collect::<Vec<_>>();
+
+        assert_eq!(holds("x<3"), [0, 1, 2]);
+        assert_eq!(holds("x>3"), [4, 5]);
+        assert_eq!(holds("x<=3"), [0, 1, 2, 3]);
+        assert_eq!(holds("x>=3"), [3, 4, 5]);
+        assert_eq!(holds("x==3"), [3]);
+
+        let max = Condition { category: "x", operator: Operator::GE, value: MAX_RATING };
+        assert!(max.holds(MAX_RATING));
+        assert!(Condition { category: "x", operator: Operator::GT, value: MAX_RATING - 1 }.holds(MAX_RATING));
+        assert!(Condition { category: "x", operator: Operator::LE, value: Value::MAX }.holds(MAX_RATING));
+    }
+
+    #[test]
+    fn test_workflow_names() {
+        assert_eq!(Workflow::new("px{A}").unwrap().name, "px");
+        for input in ["{A}", "A{R}", "R{A}", "\"p\"{A}", "p x{A}"] {
+            assert!(Workflow::new(input).is_err(), "{}", input);
+        }
+
+        assert!(parse("in{px}\npx{A}\nin{R}\n\n").is_err());
+    }
+
+    #[test]
+    fn test_largest_ratings() {
+        assert!(Part::new(&format!("{{x={}}}", Value::MAX)).is_err());
+
+        let input = format!("in{{x>=5:A,R}}\n\n{{x={}}}", MAX_RATING);
+        let (workflows, parts) = parse(&input).unwrap();
+        assert_eq!(evaluate(&workflows, &parts[0]), Ok(Target::Accept));
+
+        let domain = Domain::new([("x", MAX_RATING - 9..=MAX_RATING)]);
+        assert_eq!(count_accepted(&workflows, &domain), Ok(10));
+
+        let domain = Domain::new([("x", Value::MIN..=MAX_RATING)]);
+        assert_eq!(count_accepted(&workflows, &domain), Ok(MAX_RATING as u128 - 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "goes past the largest rating")]
+    fn test_domain_too_large() {
+        Domain::new([("x", 0..=Value::MAX)]);
+    }
+
+    #[test]
+    fn test_parse_part() {
+        let part = Part::new("{hue=3,weight=10}").unwrap();
+        assert_eq!(part.get("hue"), Some(3));
+        assert_eq!(part.get("x"), None);
+        assert_eq!(part.value(), Some(13));
+
+        assert!(Part::new("{x=1,x=2}").is_err());
+        assert!(Part::new("{x=1").is_err());
+    }
+
+    #[test]
+    fn test_other_categories() {
+        let input = "in{hue==2:A,weight>=8:hot,R}
+hot{hue<=1:R,A}
+
+{hue=2,weight=1}
+{hue=1,weight=9}
+{hue=3,weight=8}";
+        let (workflows, parts) = parse(input).unwrap();
+
+        let verdicts = parts.iter()
+            .map(|part| evaluate(&workflows, part).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(verdicts, [Target::Accept, Target::Reject, Target::Accept]);
+
+        // hue 2 with any weight, or hue 3 with weights 8 and 9
+        let domain = Domain::new([("hue", 0..=3), ("weight", 0..=9)]);
+        assert_eq!(count_accepted(&workflows, &domain), Ok(10 + 2));
+    }
+
+    #[test]
+    fn test_trace() {
+        let (workflows, parts) = parse("in{s<1351:px,qqz}
+px{a<2006:qkq,m>2090:A,rfg}
+qqz{s>2770:qs,m<1801:hdj,R}
+qs{s>3448:A,lnx}
+lnx{m>1548:A,A}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+rfg{s<537:gd,x>2440:R,A}
+gd{a>3333:R,R}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}").unwrap();
+
+        let accepted = trace(&workflows, &parts[0]).unwrap();
+        assert_eq!(accepted.workflows().collect::<Vec<_>>(), ["in", "qqz", "qs", "lnx"]);
+        assert_eq!(accepted.verdict(), Target::Accept);
+        assert_eq!(accepted.steps[0], Step { workflow: "in", rule: 1, comparison: None, target: Target::Workflow("qqz") });
+        assert_eq!(accepted.steps[1].comparison, Some((Condition { category: "s", operator: Operator::GT, value: 2770 }, 2876)));
+
+        let lines = accepted.steps.iter().map(|step| step.to_string()).collect::<Vec<_>>();
+        assert_eq!(lines, ["in[1] -> qqz", "qqz[0] s>2770 (s=2876) -> qs", "qs[1] -> lnx", "lnx[0] m>1548 (m=2655) -> A"]);
+
+        assert_eq!(evaluate(&workflows, &parts[1]), Ok(Target::Reject));
+        assert_eq!(trace(&workflows, &parts[1]).unwrap().workflows().collect::<Vec<_>>(), ["in", "px", "rfg", "gd"]);
+        assert_eq!(parts[1].to_string(), "{x=1679,m=44,a=2067,s=496}");
+    }
+
+    #[test]
+    fn test_accepted_region() {
+        let (workflows, parts) = parse("in{s<1351:px,qqz}
+px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}").unwrap();
+        let domain = Domain::xmas();
+        let region = accepted_region(&workflows, &domain).unwrap();
+
+        assert_eq!(region.volume(), 167409079868000);
+        assert!(region.contains(&domain.point(&parts[0]).unwrap()));
+        assert!(!region.contains(&domain.point(&parts[1]).unwrap()));
+        assert!(region.boxes().iter().any(|cuboid| domain.describe(cuboid) == "{x=1..=1415,m=1..=4000,a=1..=2005,s=1..=1350}"));
+    }
+
+    #[test]
+    fn test_intersect_workflow_sets() {
+        let (first, _) = parse("in{x<3:A,y>=4:low,R}\nlow{x==5:R,A}\n\n").unwrap();
+        let (second, _) = parse("in{y<=2:R,x>1:A,R}\n\n").unwrap();
+        let domain = Domain::new([("x", 0..=6), ("y", 0..=6)]);
+
+        let both = accepted_region(&first, &domain).unwrap().intersect(&accepted_region(&second, &domain).unwrap());
+        let only_first = accepted_region(&first, &domain).unwrap().subtract(&accepted_region(&second, &domain).unwrap());
+
+        let mut expected = [0, 0];
+        for x in 0..=6 {
+            for y in 0..=6 {
+                let input = format!("{{x={},y={}}}", x, y);
+                let part = Part::new(&input).unwrap();
+                let accepted = [&first, &second].map(|workflows| evaluate(workflows, &part) == Ok(Target::Accept));
+                expected[0] += (accepted[0] && accepted[1]) as u128;
+                expected[1] += (accepted[0] && !accepted[1]) as u128;
+            }
+        }
+
+        assert_eq!([both.volume(), only_first.volume()], expected);
+    }
+
+    #[test]
+    fn test_solve_errors() {
+        let err = Day19::solve_part2("\n\n").unwrap_err();
+        assert_eq!(err.to_string(), "Workflow \"in\" isn't defined");
+        assert!(Day19::solve_part1("in{x<5:a,A}\n\n{x=1}").is_err());
+    }
+
+    #[test]
+    fn test_eval_errors() {
+        let (workflows, parts) = parse("in{x<5:a,b}\na{y>2:in,A}\n\n{x=1,y=3}\n{x=1,y=1}\n{x=9}").unwrap();
+
+        assert_eq!(evaluate(&workflows, &parts[0]), Err(EvalError::Cycle("in".to_string())));
+        assert_eq!(evaluate(&workflows, &parts[1]), Ok(Target::Accept));
+        assert_eq!(evaluate(&workflows, &parts[2]), Err(EvalError::UndefinedWorkflow("b".to_string())));
+
+        let domain = Domain::new([("x", 5..=9)]);
+        assert_eq!(count_accepted(&workflows, &domain), Err(EvalError::UndefinedWorkflow("b".to_string())));
+        let domain = Domain::new([("x", 0..=4)]);
+        assert_eq!(count_accepted(&workflows, &domain), Err(EvalError::MissingRating("y".to_string())));
+        let domain = Domain::new([("x", 0..=4), ("y", 0..=9)]);
+        assert_eq!(count_accepted(&workflows, &domain), Err(EvalError::Cycle("in".to_string())));
+        let domain = Domain::new([("x", 0..=4), ("y", 0..=2)]);
+        assert_eq!(count_accepted(&workflows, &domain), Ok(5 * 3));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+
+fn run_workflows<'a, 'b>(workflows: &WorkflowMap, parts: &'a [Part<'b>]) -> Result<(Vec<&'a Part<'b>>, Vec<&'a Part<'b>>), EvalError> {
+    let mut accepted = Vec::new();
+    let mut rejected = Vec::new();
+    for part in parts {
+        match evaluate(workflows, part)? {
+            Target::Accept => accepted.push(part),
+            _ => rejected.push(part),
+        }
+    }
+    Ok((accepted, rejected))
+}
+
+pub fn solve(workflows: &WorkflowMap, parts: &[Part]) -> Result<Value, EvalError> {
+    let (accepted, _rejected) = run_workflows(workflows, parts)?;
+
+    accepted.iter()
+        .try_fold(0, |sum: Value, part| part.value().and_then(|value| sum.checked_add(value)))
+        .ok_or(EvalError::Overflow)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    static TEST_INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_input_1() {
+        let input = TEST_INPUT;
+        let (workflows, parts) = parse(input).unwrap();
+        let (accepted, _rejected) = run_workflows(&workflows, &parts).unwrap();
+
+        let result = accepted.iter()
+            .map(|part| part.value().unwrap())
+            .sum::<Value>();
+
+        assert_eq!(result, 19114);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = TEST_INPUT;
+        parse(input).unwrap();
+    }
+
+    #[test]
+    fn test_parse_workflow() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}";
+        let workflow = Workflow::new(input).unwrap();
+
+        assert_eq!(workflow.name, "px");
+        assert_eq!(workflow.rules[0], Rule {
+            condition: Some(Condition { category: "a", operator: Operator::LT, value: 2006 }),
+            target: Target::Workflow("qkq"),
+        });
+        assert_eq!(workflow.rules[1], Rule {
+            condition: Some(Condition { category: "m", operator: Operator::GT, value: 2090 }),
+            target: Target::Accept,
+        });
+        assert_eq!(workflow.rules[2], Rule {
+            condition: None,
+            target: Target::Workflow("rfg"),
+        });
+        assert_eq!(workflow.to_string(), input);
+    }
+
+    #[test]
+    fn test_apply_workflow_1() {
+        let workflow = Workflow::new("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+        let part = Part::new("{x=787,m=2655,a=1222,s=2876}").unwrap();
+        let destination = workflow.apply(&part).unwrap();
+        assert_eq!(destination, (0, Target::Workflow("qkq")));
+    }
+
+    #[test]
+    fn test_apply_workflow_2() {
+        let workflow = Workflow::new("px{a<2006:qkq,m>2090:A,rfg}").unwrap();
+        let part = Part::new("{x=1679,m=44,a=2067,s=496}").unwrap();
+        let destination = workflow.apply(&part).unwrap();
+        assert_eq!(destination, (2, Target::Workflow("rfg")));
+    }
+
+    #[test]
+    fn test_overflow() {
+        let (workflows, parts) = parse("in{A}\n\n{x=9223372036854775806,m=9223372036854775806}").unwrap();
+        assert_eq!(solve(&workflows, &parts), Err(EvalError::Overflow));
+
+        let (workflows, parts) = parse("in{A}\n\n{x=9223372036854775806}\n{x=1}\n{x=1}").unwrap();
+        assert_eq!(solve(&workflows, &parts), Err(EvalError::Overflow));
+
+        let (workflows, parts) = parse("in{A}\n\n{x=9223372036854775806,m=-5}\n{x=1}").unwrap();
+        assert_eq!(solve(&workflows, &parts), Ok(9223372036854775802));
+    }
+}
\ No newline at end of file
@@ -0,0 +1,134 @@
+use crate::*;
+
+pub fn solve(workflows: &WorkflowMap) -> Result<u128, EvalError> {
+    count_accepted(workflows, &Domain::xmas())
+}
+
+#[cfg(test)]
+mod test {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use super::*;
+
+    static TEST_INPUT: &str = "px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}
+{x=2036,m=264,a=79,s=2244}
+{x=2461,m=1339,a=466,s=291}
+{x=2127,m=1623,a=2188,s=1013}";
+
+    #[test]
+    fn test_input_1() {
+        let input = TEST_INPUT;
+        let (workflows, _parts) = parse(input).unwrap();
+        let result = solve(&workflows).unwrap();
+        assert_eq!(result, 167409079868000);
+    }
+
+    #[test]
+    fn test_input_2() {
+        let input = "in{x>3998:pb,R}
+pb{m>3998:pc,R}
+pc{a>3998:pd,R}
+pd{s>3998:A,R}
+
+";
+        let (workflows, _parts) = parse(input).unwrap();
+        let result = solve(&workflows).unwrap();
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn test_input_3() {
+        let input = "in{x>3999:pb,x<2:pe,R}
+pb{m>3999:pc,R}
+pc{a>3999:pd,R}
+pd{s>3999:A,R}
+pe{m<2:pf,R}
+pf{a<2:pg,R}
+pg{s<2:A,R}
+
+";
+        let (workflows, _parts) = parse(input).unwrap();
+        let result = solve(&workflows).unwrap();
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_input_4() {
+        let input = "in{x>3998:pb,x<3:pe,R}
+pb{m>3998:pc,R}
+pc{a>3998:pd,R}
+pd{s>3998:A,R}
+pe{m<3:pf,R}
+pf{a<3:pg,R}
+pg{s<3:A,R}
+
+";
+        let (workflows, _parts) = parse(input).unwrap();
+        let result = solve(&workflows).unwrap();
+        assert_eq!(result, 32);
+    }
+
+    #[test]
+    fn test_input_5() {
+        let input = "in{x>3998:pb,x>3998:pe,R}
+pb{m>3998:pc,R}
+pc{a>3998:pd,R}
+pd{s>3998:A,R}
+pe{m>3998:pf,R}
+pf{a>3998:pg,R}
+pg{s>3998:A,R}
+
+";
+        let (workflows, _parts) = parse(input).unwrap();
+        let result = solve(&workflows).unwrap();
+        assert_eq!(result, 16);
+    }
+
+    #[test]
+    fn test_parse() {
+        let input = TEST_INPUT;
+        parse(input).unwrap();
+    }
+
+    #[test]
+    fn test_parse_workflow() {
+        let input = "px{a<2006:qkq,m>2090:A,rfg}";
+        let workflow = Workflow::new(input).unwrap();
+
+        assert_eq!(workflow.name, "px");
+        assert_eq!(workflow.rules[0], Rule {
+            condition: Some(Condition { category: "a", operator: Operator::LT, value: 2006 }),
+            target: Target::Workflow("qkq"),
+        });
+        assert_eq!(workflow.rules[1], Rule {
+            condition: Some(Condition { category: "m", operator: Operator::GT, value: 2090 }),
+            target: Target::Accept,
+        });
+        assert_eq!(workflow.rules[2], Rule {
+            condition: None,
+            target: Target::Workflow("rfg"),
+        });
+        assert_eq!(workflow.to_string(), input);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..10 {
+            let puzzle = gen::workflows(seed, 50, 100);
+            assert!(Day19::solve_part2(&puzzle.input).unwrap() <= 4000u128.pow(4));
+        }
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+use std::ops::Range;
+use crate::Value;
+
+/// An axis aligned box of parts, as the half-open range of ratings in each category of a [`Domain`](crate::Domain)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid {
+    pub ranges: Vec<Range<Value>>,
+}
+
+impl Cuboid {
+    pub fn new(ranges: impl IntoIterator<Item=Range<Value>>) -> Self {
+        Self {
+            ranges: ranges.into_iter().collect(),
+        }
+    }
+
+    pub fn dimensions(&self) -> usize {
+        self.ranges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_empty())
+    }
+
+    /// How many parts are in the box, the widths are taken in `i128` as a range can span every `Value`
+    pub fn volume(&self) -> u128 {
+        self.ranges.iter().map(|range| (range.end as i128 - range.start as i128).max(0) as u128).product()
+    }
+
+    pub fn contains(&self, point: &[Value]) -> bool {
+        self.ranges.iter().zip(point).all(|(range, value)| range.contains(value))
+    }
+
+    fn with(&self, dimension: usize, range: Range<Value>) -> Cuboid {
+        let mut cuboid = self.clone();
+        cuboid.ranges[dimension] = range;
+        cuboid
+    }
+
+    pub fn intersect(&self, other: &Cuboid) -> Option<Cuboid> {
+        debug_assert_eq!(self.dimensions(), other.dimensions());
+        let cuboid = Cuboid::new(self.ranges.iter().zip(&other.ranges)
+            .map(|(a, b)| a.start.max(b.start)..a.end.min(b.end)));
+        (!cuboid.is_empty()).then_some(cuboid)
+    }
+
+    /// Splits the box into the part with ratings in `matching` along `dimension`, and the disjoint boxes of the rest
+    pub fn split(&self, dimension: usize, matching: &Range<Value>) -> (Option<Cuboid>, Vec<Cuboid>) {
+        debug_assert!(dimension < self.dimensions(), "Splitting along dimension {} of a {} dimensional box", dimension, self.dimensions());
+        let range = &self.ranges[dimension];
+        let inside = range.start.max(matching.start)..range.end.min(matching.end);
+        let outside = [range.start..range.end.min(matching.start), range.start.max(matching.end)..range.end];
+
+        (
+            (!inside.is_empty()).then(|| self.with(dimension, inside)),
+            outside.into_iter().filter(|piece| !piece.is_empty()).map(|piece| self.with(dimension, piece)).collect(),
+        )
+    }
+
+    /// Everything in `self` but not in `other`, as at most two disjoint boxes per dimension
+    pub fn subtract(&self, other: &Cuboid) -> Vec<Cuboid> {
+        debug_assert_eq!(self.dimensions(), other.dimensions());
+        let Some(overlap) = self.intersect(other) else {
+            return vec![self.clone()];
+        };
+
+        // Peel off the slabs either side of the overlap one dimension at a time, narrowing what's left down to it
+        let mut pieces = Vec::new();
+        let mut rest = self.clone();
+        for (dimension, cut) in overlap.ranges.iter().enumerate() {
+            let (_, outside) = rest.split(dimension, cut);
+            pieces.extend(outside);
+            rest.ranges[dimension] = cut.clone();
+        }
+
+        pieces
+    }
+}
+
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("[")?;
+        for (idx, range) in self.ranges.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "{}..={}", range.start, range.end - 1)?;
+        }
+        f.write_str("]")
+    }
+}
+
+/// A set of parts, as disjoint boxes
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BoxSet {
+    boxes: Vec<Cuboid>,
+}
+
+impl BoxSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Boxes the caller knows to be disjoint, empty ones are dropped
+    pub(crate) fn from_disjoint(boxes: impl IntoIterator<Item=Cuboid>) -> Self {
+        Self {
+            boxes: boxes.into_iter().filter(|cuboid| !cuboid.is_empty()).collect(),
+        }
+    }
+
+    pub fn boxes(&self) -> &[Cuboid] {
+        &self.boxes
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.boxes.is_empty()
+    }
+
+    pub fn volume(&self) -> u128 {
+        self.boxes.iter().map(Cuboid::volume).sum()
+    }
+
+    pub fn contains(&self, point: &[Value]) -> bool {
+        self.boxes.iter().any(|cuboid| cuboid.contains(point))
+    }
+
+    /// Adds the parts of `cuboid` not already in the set
+    pub fn insert(&mut self, cuboid: Cuboid) {
+        let mut pieces = vec![cuboid];
+        for existing in &self.boxes {
+            pieces = pieces.iter().flat_map(|piece| piece.subtract(existing)).collect();
+        }
+        self.boxes.extend(pieces.into_iter().filter(|piece| !piece.is_empty()));
+    }
+
+    pub fn intersect(&self, other: &BoxSet) -> BoxSet {
+        BoxSet::from_disjoint(self.boxes.iter()
+            .flat_map(|a| other.boxes.iter().filter_map(|b| a.intersect(b))))
+    }
+
+    pub fn subtract(&self, other: &BoxSet) -> BoxSet {
+        BoxSet::from_disjoint(self.boxes.iter().flat_map(|cuboid| {
+            other.boxes.iter().fold(vec![cuboid.clone()], |pieces, existing| {
+                pieces.iter().flat_map(|piece| piece.subtract(existing)).collect()
+            })
+        }))
+    }
+
+    pub fn union(&self, other: &BoxSet) -> BoxSet {
+        let mut union = self.clone();
+        union.boxes.extend(other.subtract(self).boxes);
+        union
+    }
+}
+
+impl FromIterator<Cuboid> for BoxSet {
+    fn from_iter<T: IntoIterator<Item=Cuboid>>(iter: T) -> Self {
+        let mut set = BoxSet::new();
+        for cuboid in iter {
+            set.insert(cuboid);
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn cuboid() -> impl Strategy<Value=Cuboid> {
+        prop::array::uniform3((0..8 as Value, 1..5 as Value))
+            .prop_map(|ranges| Cuboid::new(ranges.map(|(start, len)| start..start + len)))
+    }
+
+    fn box_set() -> impl Strategy<Value=BoxSet> {
+        prop::collection::vec(cuboid(), 0..5).prop_map(BoxSet::from_iter)
+    }
+
+    /// Every point of the 3 dimensional grid the random boxes live in
+    fn points() -> impl Iterator<Item=[Value; 3]> {
+        (0..13).flat_map(|x| (0..13).flat_map(move |y| (0..13).map(move |z| [x, y, z])))
+    }
+
+    fn is_disjoint(set: &BoxSet) -> bool {
+        set.boxes().iter().enumerate()
+            .all(|(idx, a)| set.boxes()[idx + 1..].iter().all(|b| a.intersect(b).is_none()))
+    }
+
+    #[test]
+    fn test_cuboid() {
+        let a = Cuboid::new([0..4, 0..4]);
+        let b = Cuboid::new([2..6, 1..3]);
+
+        assert_eq!(a.volume(), 16);
+        assert_eq!(a.intersect(&b), Some(Cuboid::new([2..4, 1..3])));
+        assert_eq!(a.intersect(&Cuboid::new([4..6, 0..4])), None);
+
+        let pieces = a.subtract(&b);
+        assert_eq!(pieces, [Cuboid::new([0..2, 0..4]), Cuboid::new([2..4, 0..1]), Cuboid::new([2..4, 3..4])]);
+        assert_eq!(pieces.iter().map(Cuboid::volume).sum::<u128>(), 12);
+
+        assert_eq!(a.split(1, &(3..10)), (Some(Cuboid::new([0..4, 3..4])), vec![Cuboid::new([0..4, 0..3])]));
+        assert_eq!(a.to_string(), "[0..=3, 0..=3]");
+
+        assert_eq!(Cuboid::new([Value::MIN..Value::MAX, 0..2]).volume(), 2 * u64::MAX as u128);
+        assert_eq!(Cuboid::new([Value::MIN..0, 3..3]).volume(), 0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_set_operations(a in box_set(), b in box_set()) {
+            let (intersection, difference, union) = (a.intersect(&b), a.subtract(&b), a.union(&b));
+
+            for set in [&a, &b, &intersection, &difference, &union] {
+                prop_assert!(is_disjoint(set));
+                prop_assert_eq!(set.volume(), points().filter(|point| set.contains(point)).count() as u128);
+            }
+
+            for point in points() {
+                let (in_a, in_b) = (a.contains(&point), b.contains(&point));
+                prop_assert_eq!(intersection.contains(&point), in_a && in_b);
+                prop_assert_eq!(difference.contains(&point), in_a && !in_b);
+                prop_assert_eq!(union.contains(&point), in_a || in_b);
+            }
+        }
+
+        #[test]
+        fn prop_cuboid_subtract(a in cuboid(), b in cuboid()) {
+            let pieces = BoxSet::from_disjoint(a.subtract(&b));
+            prop_assert!(is_disjoint(&pieces));
+            for point in points() {
+                prop_assert_eq!(pieces.contains(&point), a.contains(&point) && !b.contains(&point));
+            }
+        }
+    }
+}
@@ -0,0 +1,277 @@
+use rustc_hash::FxHashMap;
+use aoc2023::search;
+use crate::analysis::constant_verdict;
+use crate::*;
+
+/// An equivalent set of workflows for parts rated within `domain`, usually a much smaller one.
+/// Workflows that always give the same verdict are replaced by it, rules that never apply are dropped,
+/// neighbouring rules sending parts to the same place are merged, and workflows only used as another's
+/// last rule are inlined into it. Workflows `in` can't reach are left out.
+pub fn simplify<'a>(workflows: &WorkflowMap<'a>, domain: &Domain) -> WorkflowMap<'a> {
+    let mut workflows = workflows.clone();
+
+    loop {
+        let before = workflows.clone();
+
+        collapse_constant(&mut workflows, domain);
+        for workflow in workflows.values_mut() {
+            drop_dead_rules(workflow, domain);
+            merge_rules(workflow);
+        }
+        while inline_single_use(&mut workflows) {}
+        drop_unreachable(&mut workflows);
+
+        if workflows == before {
+            return workflows;
+        }
+    }
+}
+
+/// Replaces workflows that always give the same verdict by it, and skips workflows that only forward parts elsewhere
+fn collapse_constant<'a>(workflows: &mut WorkflowMap<'a>, domain: &Domain) {
+    let constant = workflows.keys()
+        .filter_map(|&name| Some((name, constant_verdict(workflows, domain, name)?)))
+        .collect::<Vec<_>>();
+
+    for (name, verdict) in constant {
+        workflows.get_mut(name).unwrap().rules = vec![Rule { condition: None, target: verdict }];
+    }
+
+    let forwards = workflows.iter()
+        .filter_map(|(&name, workflow)| match workflow.rules[..] {
+            [Rule { condition: None, target }] if target != Target::Workflow(name) => Some((name, target)),
+            _ => None,
+        })
+        .collect::<FxHashMap<_, _>>();
+
+    for workflow in workflows.values_mut() {
+        for rule in &mut workflow.rules {
+            if let Target::Workflow(name) = rule.target {
+                if let Some(&target) = forwards.get(name) {
+                    rule.target = target;
+                }
+            }
+        }
+    }
+}
+
+/// Drops the rules no part in `domain` gets to with its condition met, and the conditions every part gets past
+fn drop_dead_rules(workflow: &mut Workflow, domain: &Domain) {
+    let mut remaining = vec![domain.cuboid()];
+    let mut rules = Vec::new();
+
+    for rule in &workflow.rules {
+        let Some(condition) = rule.condition else {
+            rules.push(*rule);
+            break;
+        };
+        let Some(category) = domain.index(condition.category) else {
+            rules.push(*rule);
+            continue;
+        };
+
+        let (mut matched, mut rest) = (false, Vec::new());
+        for cuboid in &remaining {
+            let (inside, outside) = cuboid.split(category, &condition.matching());
+            matched |= inside.is_some();
+            rest.extend(outside);
+        }
+
+        if !matched {
+            continue;
+        }
+        // Every part left meets the condition, so the rules after it never apply
+        if rest.is_empty() {
+            rules.push(Rule { condition: None, target: rule.target });
+            break;
+        }
+
+        rules.push(*rule);
+        remaining = rest;
+    }
+
+    // Parts outside the domain are never sorted, but every workflow still needs a final rule
+    if rules.last().is_none_or(|rule| rule.condition.is_some()) {
+        rules.push(*workflow.rules.last().unwrap());
+    }
+    workflow.rules = rules;
+}
+
+/// The single rule doing the same as `first` followed by `second`, when one condition can say it
+fn merge<'a>(first: &Rule<'a>, second: &Rule<'a>) -> Option<Rule<'a>> {
+    let (Some(a), Some(b)) = (first.condition, second.condition) else {
+        return None;
+    };
+    if first.target != second.target || a.category != b.category {
+        return None;
+    }
+
+    let (a, b) = (a.matching(), b.matching());
+    if a.start.max(b.start) > a.end.min(b.end) {
+        return None;
+    }
+    let union = a.start.min(b.start)..a.end.max(b.end);
+
+    let condition = |operator, value| Some(Condition { category: first.condition.unwrap().category, operator, value });
+    let condition = match (union.start, union.end) {
+        (Value::MIN, Value::MAX) => None,
+        (Value::MIN, end) => condition(Operator::LT, end),
+        (start, Value::MAX) => condition(Operator::GT, start - 1),
+        (start, end) if end - start == 1 => condition(Operator::EQ, start),
+        _ => return None,
+    };
+
+    Some(Rule { condition, target: first.target })
+}
+
+/// Merges neighbouring rules with the same target, and drops the rules sending parts where the last rule would anyway
+fn merge_rules(workflow: &mut Workflow) {
+    let mut rules: Vec<Rule> = Vec::new();
+
+    for rule in &workflow.rules {
+        match rules.last().and_then(|last| merge(last, rule)) {
+            Some(merged) => *rules.last_mut().unwrap() = merged,
+            None => rules.push(*rule),
+        }
+        if rules.last().unwrap().condition.is_none() {
+            break;
+        }
+    }
+
+    let fallback = rules.pop().unwrap();
+    while rules.last().is_some_and(|rule| rule.target == fallback.target) {
+        rules.pop();
+    }
+    rules.push(fallback);
+
+    workflow.rules = rules;
+}
+
+/// Inlines a workflow only ever used as another one's last rule, returns whether there was one
+fn inline_single_use(workflows: &mut WorkflowMap) -> bool {
+    let mut uses: FxHashMap<&str, Vec<(&str, usize)>> = FxHashMap::default();
+    for (&name, workflow) in workflows.iter() {
+        for (idx, rule) in workflow.rules.iter().enumerate() {
+            if let Target::Workflow(target) = rule.target {
+                uses.entry(target).or_default().push((name, idx));
+            }
+        }
+    }
+
+    let mut candidates = uses.into_iter()
+        .filter_map(|(name, uses)| match uses[..] {
+            [(user, idx)] if name != "in" && user != name && workflows.contains_key(name)
+                && idx == workflows[user].rules.len() - 1 => Some((name, user)),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    // Inline in a fixed order, whatever order the map iterates in
+    candidates.sort_unstable();
+
+    let Some(&(name, user)) = candidates.first() else {
+        return false;
+    };
+
+    let inlined = workflows.remove(name).unwrap();
+    let user = workflows.get_mut(user).unwrap();
+    user.rules.pop();
+    user.rules.extend(inlined.rules);
+    true
+}
+
+fn drop_unreachable(workflows: &mut WorkflowMap) {
+    if !workflows.contains_key("in") {
+        return;
+    }
+
+    let reachable = search::bfs_distances(["in"], |&name| {
+        workflows.get(name).into_iter()
+            .flat_map(|workflow| workflow.rules.iter())
+            .filter_map(|rule| match rule.target {
+                Target::Workflow(target) => Some(target),
+                _ => None,
+            })
+            .collect::<Vec<_>>()
+    });
+    workflows.retain(|name, _| reachable.contains_key(name));
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use super::*;
+
+    fn sorted<'a>(workflows: &WorkflowMap<'a>) -> Vec<String> {
+        let mut workflows = workflows.values().map(|workflow| workflow.to_string()).collect::<Vec<_>>();
+        workflows.sort();
+        workflows
+    }
+
+    #[test]
+    fn test_merge_rules() {
+        let mut workflow = Workflow::new("w{x<5:a,x<10:a,m>3:R,m>10:R,s==3:b,s==4:b,s>1:A,A}").unwrap();
+        merge_rules(&mut workflow);
+        assert_eq!(workflow.to_string(), "w{x<10:a,m>3:R,s==3:b,s==4:b,A}");
+
+        let mut workflow = Workflow::new("w{x<5:a,x>4:a,R}").unwrap();
+        merge_rules(&mut workflow);
+        assert_eq!(workflow.to_string(), "w{a}");
+    }
+
+    #[test]
+    fn test_drop_dead_rules() {
+        let mut workflow = Workflow::new("w{x>4000:a,x<2000:b,x>=2000:c,R}").unwrap();
+        drop_dead_rules(&mut workflow, &Domain::xmas());
+        assert_eq!(workflow.to_string(), "w{x<2000:b,c}");
+    }
+
+    #[test]
+    fn test_example() {
+        let (workflows, parts) = parse("px{a<2006:qkq,m>2090:A,rfg}
+pv{a>1716:R,A}
+lnx{m>1548:A,A}
+rfg{s<537:gd,x>2440:R,A}
+qs{s>3448:A,lnx}
+qkq{x<1416:A,crn}
+crn{x>2662:A,R}
+in{s<1351:px,qqz}
+qqz{s>2770:qs,m<1801:hdj,R}
+gd{a>3333:R,R}
+hdj{m>838:A,pv}
+unused{A}
+
+{x=787,m=2655,a=1222,s=2876}
+{x=1679,m=44,a=2067,s=496}").unwrap();
+        let simplified = simplify(&workflows, &Domain::xmas());
+
+        // gd always rejects, qs and lnx always accept and the rest are only used once
+        assert_eq!(sorted(&simplified), [
+            "hdj{m>838:A,a>1716:R,A}",
+            "in{s<1351:px,s>2770:A,m<1801:hdj,R}",
+            "px{a<2006:qkq,m>2090:A,s<537:R,x>2440:R,A}",
+            "qkq{x<1416:A,x>2662:A,R}",
+        ]);
+
+        for part in &parts {
+            assert_eq!(evaluate(&simplified, part), evaluate(&workflows, part));
+        }
+    }
+
+    #[test]
+    fn test_generated() {
+        let domain = Domain::xmas();
+
+        for seed in 0..20 {
+            let puzzle = gen::workflows(seed, 60, 200);
+            let (workflows, parts) = parse(&puzzle.input).unwrap();
+            let simplified = simplify(&workflows, &domain);
+
+            assert!(simplified.len() <= workflows.len());
+            let (before, after) = (accepted_region(&workflows, &domain).unwrap(), accepted_region(&simplified, &domain).unwrap());
+            assert!(before.subtract(&after).is_empty() && after.subtract(&before).is_empty(), "seed {}", seed);
+            for part in &parts {
+                assert_eq!(evaluate(&simplified, part), evaluate(&workflows, part), "seed {} part {}", seed, part);
+            }
+        }
+    }
+}
@@ -1,7 +1,32 @@
 use std::collections::HashMap;
 use std::collections::hash_map::Iter;
 use itertools::Itertools;
-use regex::Regex;
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Game<'a>>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse::lines(input, Game::new).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(games: &Self::Parsed<'_>) -> miette::Result<u32> {
+        Ok(part1::solve(games))
+    }
+
+    fn part2(games: &Self::Parsed<'_>) -> miette::Result<u32> {
+        Ok(part2::solve(games))
+    }
+}
 
 #[derive(Debug)]
 pub struct Grab<'a> {
@@ -9,19 +34,19 @@ pub struct Grab<'a> {
 }
 
 impl<'a> Grab<'a> {
-    pub fn from_str(input: &'a str) -> Self {
+    /// `input` is a slice of the game's `line`, which errors are reported against
+    pub fn new(line: &str, input: &'a str) -> Result<Self, ParseError> {
         let mut cubes = HashMap::new();
 
-        for part in input.split(",") {
-            if let Some((n, color)) = part.split_whitespace().collect_tuple() {
-                let num: u32 = n.parse().unwrap();
-                cubes.insert(color.into(), num);
-            }
+        for part in input.split(',') {
+            let (n, color) = part.split_whitespace().collect_tuple()
+                .ok_or_else(|| ParseError::expected("a count and a color", line, part))?;
+            cubes.insert(color, parse::number(line, n)?);
         };
 
-        Grab {
+        Ok(Grab {
             cubes
-        }
+        })
     }
 
     fn items(&self) -> Iter<'_, &'a str, u32> {
@@ -36,21 +61,19 @@ pub struct Game<'a> {
 }
 
 impl<'a> Game<'a> {
-    pub fn from_str(input: &'a str) -> Self {
-        let re = Regex::new(r"^Game (?<id>\d+):\s(?<rest>.*)$").unwrap();
-        let Some(caps) = re.captures(input) else {
-            panic!("Invalid input");
-        };
-
-        let id = caps["id"].parse::<u32>().expect("Couldn't parse game id to int");
-        let rest = caps.name("rest").unwrap().as_str();
+    pub fn new(input: &'a str) -> Result<Self, ParseError> {
+        let rest = parse::strip_prefix(input, input, "Game ")?;
+        let (id, rest) = parse::split_once(input, rest, ": ")?;
 
-        let grabs: Vec<Grab> = rest.split(";").map(|grab_str| Grab::from_str(grab_str)).collect();
+        let id = parse::number(input, id)?;
+        let grabs = rest.split(';')
+            .map(|grab| Grab::new(input, grab))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Game {
+        Ok(Game {
             id,
             grabs,
-        }
+        })
     }
 
     pub fn get_max_counts(&self) -> HashMap<&'a str, u32> {
@@ -75,13 +98,12 @@ impl<'a> Game<'a> {
         let max_counts = self.get_max_counts();
 
         max_counts.iter()
-            .map(|(color, draw_count)| {
+            .all(|(color, draw_count)| {
                 if let Some(max_count) = bag_contains.get(color) {
                     draw_count <= max_count
                 } else {
                     false
                 }
             })
-            .all(|x| x)
     }
-}
\ No newline at end of file
+}
@@ -1,25 +1,23 @@
 use std::collections::HashMap;
-use day2::*;
-
-fn main() {
-    let input = include_str!("../input1.txt");
+use crate::*;
 
+pub fn solve(games: &[Game]) -> u32 {
     let bag_contents = HashMap::from([
         ("red", 12),
         ("green", 13),
         ("blue", 14)
     ]);
 
-    let sum_ids = input.lines()
-        .map(|line| Game::from_str(line))
+    games.iter()
         .filter(|game| game.is_possible(&bag_contents))
-        .fold(0, |acc, el| acc + el.id);
-
-    println!("Sum of possible Game IDs: {}", sum_ids);
+        .fold(0, |acc, el| acc + el.id)
 }
 
 #[cfg(test)]
 mod tests {
+    use aoc2023::gen;
+    use crate::Day2;
+    use aoc2023::Solution;
     use super::*;
 
     #[test]
@@ -36,12 +34,10 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
             ("blue", 14)
         ]);
 
-        let games: Vec<Game> = input.lines()
-            .map(|line| Game::from_str(line))
-            .collect();
+        let games = Day2::parse(input).unwrap();
 
         let possible_games: Vec<&Game> = games.iter()
-            .filter_map(|game| if game.is_possible(&bag_contents) { Some(game) } else { None })
+            .filter(|game| game.is_possible(&bag_contents))
             .collect();
 
         for game in &possible_games {
@@ -53,4 +49,23 @@ Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
         assert_eq!(summed_ids, 8)
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_invalid_game() {
+        let input = "Game 1: 3 blue, 4 red
+Game 2: 1 blue; two green";
+
+        let err = Game::new("Game 3: 4 red, blue").unwrap_err();
+        assert_eq!(err.to_string(), "Expected a count and a color at line 1, column 15");
+
+        let err = Day2::parse(input).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number \"two\" at line 2, column 17");
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::cube_games(2, 100);
+        assert!(Day2::solve_part1(&puzzle.input).is_ok());
+        assert!(Day2::solve_part2(&puzzle.input).is_ok());
+    }
+}
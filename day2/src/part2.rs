@@ -1,22 +1,18 @@
-use day2::*;
+use crate::*;
 
-fn main() {
-    let input = include_str!("../input1.txt");
-
-    let sum_power: u32 = input.lines()
-        .map(|line| Game::from_str(line))
+pub fn solve(games: &[Game]) -> u32 {
+    games.iter()
         .map(|game| game.get_max_counts()
             .iter()
-            .fold(1, |acc, (color, count)| acc * count)
+            .fold(1, |acc, (_color, count)| acc * count)
         )
-        .sum();
-
-    println!("Sum of Game powers: {}", sum_power);
+        .sum()
 }
 
 #[cfg(test)]
 mod tests {
 
+    use aoc2023::Solution;
     use super::*;
 
     #[test]
@@ -28,18 +24,16 @@ Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
 Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green";
 
 
-        let games: Vec<Game> = input.lines()
-            .map(|line| Game::from_str(line))
-            .collect();
+        let games = Day2::parse(input).unwrap();
 
         let game_powers = games.iter()
             .map(|game| game.get_max_counts()
                 .iter()
-                .fold(1, |acc, (color, count)| acc * count)
+                .fold(1, |acc, (_color, count)| acc * count)
             )
             .collect::<Vec<u32>>();
 
         assert_eq!(game_powers.iter().sum::<u32>(), 2286);
 
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+use glam::IVec2;
+use aoc2023::error::ParseError;
+use aoc2023::map2d::{Edges, Map2D};
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Map2D<char>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(map: &Self::Parsed<'_>) -> miette::Result<u32> {
+        Ok(part1::solve(map))
+    }
+
+    fn part2(map: &Self::Parsed<'_>) -> miette::Result<u32> {
+        Ok(part2::solve(map))
+    }
+}
+
+/// The schematic, checking every number in it fits in a `u32`
+pub fn parse(input: &str) -> Result<Map2D<char>, ParseError> {
+    let map = Map2D::parse(input, Some)?;
+    let lines = input.lines().collect::<Vec<_>>();
+
+    for (num_str, positions) in find_numbers(&map) {
+        let (x, y) = positions[0];
+        let start = lines[y].char_indices().nth(x).unwrap().0;
+        parse::number::<u32>(input, &lines[y][start..start + num_str.len()])?;
+    }
+
+    Ok(map)
+}
+
+// Assumes (x, y) is the first digit of the number
+pub fn get_number(map: &Map2D<char>, x: usize, y: usize) -> Option<(String, Vec<(usize, usize)>)> {
+    if !map.get(x, y)?.is_numeric() { return None; }
+
+    // Keep track of number positions
+    let positions = (x..map.width)
+        .take_while(|&cur_x| map.get(cur_x, y).is_some_and(|c| c.is_numeric()))
+        .map(|cur_x| (cur_x, y))
+        .collect::<Vec<_>>();
+
+    let num_str: String = map.iter_row(y).skip(x).take(positions.len()).collect();
+
+    Some((num_str, positions))
+}
+
+pub fn get_check_positions(map: &Map2D<char>, positions: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    positions.iter()
+        .flat_map(|&(x, y)| map.neighbours8(IVec2::new(x as i32, y as i32), Edges::Bounded))
+        .map(|(pos, _)| (pos.x as usize, pos.y as usize))
+        .collect::<HashSet<(usize, usize)>>()
+        .difference(&HashSet::from_iter(positions.iter().copied()))
+        .copied()
+        .collect()
+}
+
+pub fn is_part_number(map: &Map2D<char>, positions: &[(usize, usize)]) -> bool {
+    get_check_positions(map, positions).iter()
+        .filter_map(|(x, y)| map.get(*x, *y))
+        .any(|c| !c.is_numeric() && c != '.')
+}
+
+pub fn find_numbers(map: &Map2D<char>) -> Vec<(String, Vec<(usize, usize)>)> {
+    let mut all_numbers = Vec::new();
+
+    for y in 0..map.height {
+        let mut x = 0;
+        while x < map.width {
+            if let Some(found) = get_number(map, x, y) {
+                x += found.1.len();
+                all_numbers.push(found);
+            } else {
+                x += 1;
+            }
+        }
+    }
+
+    all_numbers
+}
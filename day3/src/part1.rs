@@ -0,0 +1,90 @@
+use crate::*;
+
+fn find_part_numbers(map: &Map2D<char>) -> Vec<u32> {
+    find_numbers(map).iter()
+        .filter(|(_n, p)| is_part_number(map, p))
+        .map(|(n, _p)| n.parse::<u32>().expect("numbers are checked while parsing"))
+        .collect()
+}
+
+pub fn solve(map: &Map2D<char>) -> u32 {
+    find_part_numbers(map).iter().sum::<u32>()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use aoc2023::error::Span;
+    use aoc2023::gen;
+    use crate::Day3;
+    use super::*;
+
+    #[test]
+    fn get_number() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        let map = Map2D::parse(input, Some).unwrap();
+
+        let expected_results = [
+            Some((String::from("467"), vec![(0, 0), (1, 0), (2, 0)])),
+            Some((String::from("664"), vec![(1, 9), (2, 9), (3, 9)])),
+            Some((String::from("617"), vec![(0, 4), (1, 4), (2, 4)])),
+            None,
+        ];
+
+        assert_eq!(crate::get_number(&map, 0, 0), expected_results[0]);
+        assert_eq!(crate::get_number(&map, 1, 9), expected_results[1]);
+        assert_eq!(crate::get_number(&map, 0, 4), expected_results[2]);
+        assert_eq!(crate::get_number(&map, 0, 1), expected_results[3]);
+    }
+
+    #[test]
+    fn find_numbers() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        let map = Map2D::parse(input, Some).unwrap();
+
+        let numbers = find_part_numbers(&map);
+
+        assert_eq!(numbers.iter().sum::<u32>(), 4361)
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::schematic(3, 140, 140);
+        assert!(Day3::solve_part1(&puzzle.input).is_ok());
+        assert!(Day3::solve_part2(&puzzle.input).is_ok());
+    }
+
+    #[test]
+    fn test_number_too_large() {
+        let input = "............\n99999999999*";
+        let err = crate::parse(input).unwrap_err();
+        assert_eq!(err.span(), Span::of(input, &input[13..24]));
+        assert!(Day3::solve_part1(input).is_err());
+
+        // Superscripts count as digits in the map, but aren't numbers
+        let input = "...\n.²*";
+        assert_eq!(crate::parse(input).unwrap_err().span(), Span::of(input, &input[5..7]));
+        assert!(Day3::solve_part2(input).is_err());
+    }
+}
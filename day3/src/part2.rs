@@ -0,0 +1,72 @@
+use std::collections::HashSet;
+use crate::*;
+
+fn calc_gear_scores(map: &Map2D<char>) -> u32 {
+    // Find all numbers on the map
+    let numbers = find_numbers(map);
+
+    // Create an index mapping for all the numbers on the map
+    let mut pos_num_idx_map = Map2D::from_size(map.width, map.height, 0);
+    for (idx, (_n, positions)) in numbers.iter().enumerate() {
+        for (x, y) in positions {
+            pos_num_idx_map.set(*x, *y, idx + 1);
+        }
+    }
+
+    // Find all * symbols, and check if they are valid gears
+    let potential_gear_positions = map.find_all(&'*');
+
+    // For each * symbol
+    potential_gear_positions.iter()
+        // Lookup the positions in the map to check for each '*'
+        .map(|pos| (pos, get_check_positions(map, &[*pos])))
+        .filter_map(|(_pos, check_positions)| {
+
+            // For each gear, find the array indices of the adjacent numbers
+            let adjecent_number_idxes = check_positions.iter().copied()
+                .filter_map(|(x, y)| match pos_num_idx_map.get(x, y) {
+                    Some(idx) => if idx > 0 { Some(idx - 1) } else { None },
+                    None => None
+                })
+                .collect::<HashSet<usize>>();
+
+            // If the gear has exactly two adjacent numbers, multiply them and return result
+            match adjecent_number_idxes.len() {
+                2 => Some(adjecent_number_idxes.iter()
+                    .map(|idx| numbers[*idx].0.parse::<u32>().expect("numbers are checked while parsing"))
+                    .product::<u32>()),
+                _ => None
+            }
+        })
+        .sum()
+}
+
+pub fn solve(map: &Map2D<char>) -> u32 {
+    calc_gear_scores(map)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+
+    #[test]
+    fn find_valid_gears() {
+        let input = "467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+        let map = Map2D::parse(input, Some).unwrap();
+        let gear_scores = calc_gear_scores(&map);
+
+        assert_eq!(gear_scores, 467835)
+    }
+}
@@ -1,9 +1,45 @@
 use std::cell::Cell;
 use std::collections::BTreeSet;
 use std::str::FromStr;
-use itertools::Itertools;
-use miette::{miette, Report};
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
 
+pub mod part1;
+pub mod part2;
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Vec<Card>;
+    type Answer1 = u32;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse_cards(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(cards: &Self::Parsed<'_>) -> miette::Result<u32> {
+        Ok(part1::solve(cards))
+    }
+
+    fn part2(cards: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(cards))
+    }
+}
+
+/// Parses every card, checking they're numbered from 1 in order, as part 2 finds copies by position
+pub fn parse_cards(input: &str) -> Result<Vec<Card>, ParseError> {
+    let cards = parse::lines(input, Card::from_str)?;
+
+    for ((idx, card), line) in cards.iter().enumerate().zip(input.lines()) {
+        if card.card_id as usize != idx + 1 {
+            return Err(ParseError::expected(format!("card {}", idx + 1), input, line));
+        }
+    }
+
+    Ok(cards)
+}
 
 pub struct Card {
     pub card_id: u32,
@@ -13,29 +49,18 @@ pub struct Card {
 }
 
 impl FromStr for Card {
-    type Err = Report;
+    type Err = ParseError;
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
-        let (card_str, numbers_str) = input.split(':').collect_tuple()
-            .ok_or_else(|| miette!("Invalid input format"))?;
-
-        let card_id = card_str.split_whitespace()
-            .nth(1)
-            .ok_or_else(|| miette!("Missing card ID"))?
-            .parse()
-            .map_err(|_| miette!("Invalid card ID"))?;
+        let (card_str, numbers_str) = parse::split_once(input, input, ":")?;
 
+        let card_id_str = parse::strip_prefix(input, card_str, "Card")?.trim_start();
+        let card_id = parse::number(input, card_id_str)?;
 
-        let (winning_numbers_str, card_numbers_str) = numbers_str.split(" | ").collect_tuple()
-            .ok_or_else(|| miette!("Invalid numbers format"))?;
+        let (winning_numbers_str, card_numbers_str) = parse::split_once(input, numbers_str, " | ")?;
 
-        let winning_numbers = winning_numbers_str.split_whitespace()
-            .map(|n| n.parse().map_err(|_| miette!("Invalid winning number: {}", n)))
-            .collect::<Result<BTreeSet<u32>, _>>()?;
-
-        let card_numbers = card_numbers_str.split_whitespace()
-            .map(|n| n.parse().map_err(|_| miette!("Invalid card number: {}", n)))
-            .collect::<Result<BTreeSet<u32>, _>>()?;
+        let winning_numbers = parse::numbers(input, winning_numbers_str)?.into_iter().collect();
+        let card_numbers = parse::numbers(input, card_numbers_str)?.into_iter().collect();
 
         Ok(Self {
             card_id,
@@ -68,4 +93,4 @@ impl Card {
             Some(n) => n
         }
     }
-}
\ No newline at end of file
+}
@@ -1,20 +1,14 @@
-use std::str::FromStr;
-use day4::*;
+use crate::*;
 
-fn main() {
-    let input = include_str!("../input1.txt");
-
-    let cards_score = input.lines()
-        .map(Card::from_str)
-        .map(|result| result.unwrap())
+pub fn solve(cards: &[Card]) -> u32 {
+    cards.iter()
         .map(|card| card.calc_score())
-        .sum::<u32>();
-
-    println!("Result: {}", cards_score);
+        .sum::<u32>()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::str::FromStr;
     use super::*;
 
     #[test]
@@ -35,4 +29,4 @@ Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
         assert_eq!(cards_score, 13);
     }
 
-}
\ No newline at end of file
+}
@@ -0,0 +1,69 @@
+use crate::*;
+
+fn calc_amount_scratch_cards(cards: &[Card]) -> usize {
+    let mut to_visit = Vec::from_iter(cards);
+    let mut visited = Vec::new();
+
+    while let Some(cur_card) = to_visit.pop() {
+        let num_matches = cur_card.get_num_matching();
+        let idx = cur_card.card_id as usize;
+
+        visited.push(cur_card);
+
+        // Copies past the last card are lost
+        to_visit.extend(cards.iter().skip(idx).take(num_matches));
+    }
+
+    visited.len()
+}
+
+pub fn solve(cards: &[Card]) -> usize {
+    calc_amount_scratch_cards(cards)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day4;
+    use std::str::FromStr;
+    use super::*;
+
+    #[test]
+    fn get_number() {
+        let input = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11";
+
+        let cards = input.lines()
+            .map(Card::from_str)
+            .map(|result| result.unwrap())
+            .collect::<Vec<Card>>();
+
+        let score = calc_amount_scratch_cards(cards.as_slice());
+
+        assert_eq!(score, 30);
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::scratchcards(seed, 30, 5, 8);
+            assert_eq!(Day4::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+            assert_eq!(Day4::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
+
+    #[test]
+    fn test_copies_past_the_end() {
+        // Card 1 wins copies of cards 2 and 3, but there's no card 3
+        assert_eq!(Day4::solve_part2("Card 1: 1 2 | 1 2\nCard 2: 1 | 1").unwrap(), 3);
+
+        let err = crate::parse_cards("Card 1: 1 2 | 1 2\nCard 3: 1 | 1").err().unwrap();
+        assert_eq!(err.to_string(), "Expected card 2 at line 2, column 1");
+    }
+}
@@ -0,0 +1,522 @@
+use std::ops;
+use std::str::FromStr;
+use itertools::Itertools;
+use aoc2023::error::ParseError;
+use aoc2023::{parse, search};
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = Almanac;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        input.parse().map_err(|err: ParseError| err.with_source(input))
+    }
+
+    fn part1(almanac: &Self::Parsed<'_>) -> miette::Result<u64> {
+        Ok(part1::solve(almanac))
+    }
+
+    fn part2(almanac: &Self::Parsed<'_>) -> miette::Result<u64> {
+        part2::solve(almanac)
+    }
+}
+
+/// A half-open interval of values
+pub type Interval = ops::Range<u64>;
+
+/// Every value but `u64::MAX`, which no range can reach as its end must fit in a `u64`
+const ALL: Interval = 0..u64::MAX;
+
+#[derive(Debug)]
+pub struct Range {
+    src: u64,
+    dst: u64,
+    len: u64
+}
+
+impl Range {
+    /// Fails if either end of the range doesn't fit in a `u64`, so mapping a value can never overflow
+    pub fn new(dst: u64, src: u64, len: u64) -> Option<Self> {
+        src.checked_add(len)?;
+        dst.checked_add(len)?;
+        Some(Range { src, dst, len })
+    }
+
+    pub fn src_interval(&self) -> Interval {
+        self.src..self.src + self.len
+    }
+
+    /// How far the range moves the values in it
+    pub fn offset(&self) -> i128 {
+        self.dst as i128 - self.src as i128
+    }
+
+    pub fn contains(&self, input: u64) -> bool {
+        input >= self.src && input - self.src < self.len
+    }
+
+    pub fn map(&self, input: u64) -> u64 {
+        self.dst + (input - self.src)
+    }
+}
+
+/// Maps values of the `source` category onto the `destination` category, like "seed" onto "soil"
+#[derive(Debug)]
+pub struct Mapping {
+    pub source: String,
+    pub destination: String,
+    ranges: Vec<Range>
+}
+
+impl Mapping {
+    pub fn map(&self, input: u64) -> u64 {
+        for range in &self.ranges {
+            if range.contains(input) {
+                return range.map(input)
+            }
+        }
+        input
+    }
+
+    /// Splits `interval` on the range boundaries, pairing each piece with how far it's moved.
+    /// The first range containing a value wins, like in [`Mapping::map`], and values outside every range stay put.
+    pub fn split(&self, interval: Interval) -> Vec<(Interval, i128)> {
+        let mut pieces = Vec::new();
+        let mut unmapped = vec![interval];
+
+        for range in &self.ranges {
+            let src = range.src_interval();
+            unmapped = unmapped.into_iter()
+                .flat_map(|piece| {
+                    let overlap = piece.start.max(src.start)..piece.end.min(src.end);
+                    if overlap.is_empty() {
+                        return vec![piece];
+                    }
+
+                    pieces.push((overlap.clone(), range.offset()));
+                    [piece.start..overlap.start, overlap.end..piece.end].into_iter()
+                        .filter(|rest| !rest.is_empty())
+                        .collect()
+                })
+                .collect();
+        }
+
+        pieces.extend(unmapped.into_iter().map(|piece| (piece, 0)));
+        pieces
+    }
+
+    /// Maps every value in `interval` at once, the result covers the same number of values
+    pub fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        self.split(interval).into_iter()
+            .map(|(piece, offset)| shift(&piece, offset))
+            .collect()
+    }
+
+    /// Every value mapped into `interval`, as a set of intervals.
+    /// Several values can end up on the same one, so this is a set rather than a single inverse value.
+    pub fn preimage(&self, interval: Interval) -> Vec<Interval> {
+        self.split(ALL).into_iter()
+            .filter_map(|(piece, offset)| {
+                let image = shift(&piece, offset);
+                let overlap = image.start.max(interval.start)..image.end.min(interval.end);
+                (!overlap.is_empty()).then(|| shift(&overlap, -offset))
+            })
+            .collect()
+    }
+
+    /// A single mapping doing `self` and then `next`
+    pub fn compose(&self, next: &Mapping) -> Mapping {
+        let ranges = self.split(ALL).into_iter()
+            .flat_map(|(piece, offset)| {
+                next.split(shift(&piece, offset)).into_iter()
+                    .map(move |(image, next_offset)| (shift(&image, -offset), offset + next_offset))
+            })
+            .filter(|&(_, offset)| offset != 0)
+            .map(|(piece, offset)| Range {
+                src: piece.start,
+                dst: (piece.start as i128 + offset) as u64,
+                len: piece.end - piece.start,
+            })
+            .collect();
+
+        Mapping {
+            source: self.source.clone(),
+            destination: next.destination.clone(),
+            ranges
+        }
+    }
+}
+
+/// Only ever called with offsets taken from a range covering `interval`, so the result fits
+fn shift(interval: &Interval, offset: i128) -> Interval {
+    (interval.start as i128 + offset) as u64..(interval.end as i128 + offset) as u64
+}
+
+impl FromStr for Mapping {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (header, numbers) = parse::split_once(s, s, " map:\n")?;
+        let (source, destination) = parse::split_once(s, header, "-to-")?;
+
+        let ranges = parse::lines(numbers, |line| {
+            let (dst, src, len) = parse::numbers(line, line)?.into_iter()
+                .collect_tuple()
+                .ok_or_else(|| ParseError::expected("destination, source and length", line, line))?;
+
+            Range::new(dst, src, len).ok_or_else(|| ParseError::invalid("range, overflowing u64", line, line))
+        }).map_err(|err| err.within(s, numbers))?;
+
+        Ok(Mapping{
+            source: source.to_string(),
+            destination: destination.to_string(),
+            ranges
+        })
+    }
+}
+
+/// The mappings leading from one category to another, in order
+#[derive(Debug)]
+pub struct Chain<'a> {
+    pub source: &'a str,
+    pub destination: &'a str,
+    pub mappings: Vec<&'a Mapping>,
+}
+
+impl Chain<'_> {
+    pub fn map(&self, input: u64) -> u64 {
+        self.mappings.iter().fold(input, |acc, mapping| mapping.map(acc))
+    }
+
+    pub fn map_interval(&self, interval: Interval) -> Vec<Interval> {
+        self.mappings.iter().fold(vec![interval], |intervals, mapping| {
+            intervals.into_iter()
+                .flat_map(|interval| mapping.map_interval(interval))
+                .collect()
+        })
+    }
+
+    /// Every `source` value ending up in `interval` of the `destination` category, like the seeds landing in some locations
+    pub fn preimage(&self, interval: Interval) -> Vec<Interval> {
+        self.mappings.iter().rev().fold(vec![interval], |intervals, mapping| {
+            intervals.into_iter()
+                .flat_map(|interval| mapping.preimage(interval))
+                .collect()
+        })
+    }
+
+    /// The whole chain as a single mapping
+    pub fn compose(&self) -> Mapping {
+        let identity = Mapping {
+            source: self.source.to_string(),
+            destination: self.source.to_string(),
+            ranges: Vec::new(),
+        };
+
+        self.mappings.iter().fold(identity, |acc, mapping| acc.compose(mapping))
+    }
+}
+
+#[derive(Debug)]
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub mappings: Vec<Mapping>,
+}
+
+impl Almanac {
+    /// Every category named in the almanac, in the order they first appear
+    pub fn categories(&self) -> impl Iterator<Item=&str> {
+        self.mappings.iter()
+            .flat_map(|mapping| [mapping.source.as_str(), mapping.destination.as_str()])
+            .unique()
+    }
+
+    /// The shortest chain of mappings from `source` to `destination`, if there is one
+    pub fn chain<'a>(&'a self, source: &'a str, destination: &'a str) -> Option<Chain<'a>> {
+        if !self.categories().any(|category| category == source) {
+            return None;
+        }
+
+        let path = search::bfs(
+            [source],
+            |&category| self.mappings.iter()
+                .filter(move |mapping| mapping.source == category)
+                .map(|mapping| mapping.destination.as_str()),
+            |&category| category == destination,
+        )?;
+
+        let mappings = path.states.iter().tuple_windows()
+            .map(|(&from, &to)| {
+                self.mappings.iter()
+                    .find(|mapping| mapping.source == from && mapping.destination == to)
+                    .unwrap()
+            })
+            .collect();
+
+        Some(Chain {
+            source,
+            destination,
+            mappings,
+        })
+    }
+
+
+    pub fn location(&self, seed: u64) -> u64 {
+        self.mappings.iter().fold(seed, |acc, el| el.map(acc))
+    }
+
+    /// The locations of all seeds in `seeds`, as a set of intervals
+    pub fn location_intervals(&self, seeds: Interval) -> Vec<Interval> {
+        self.mappings.iter().fold(vec![seeds], |intervals, mapping| {
+            intervals.into_iter()
+                .flat_map(|interval| mapping.map_interval(interval))
+                .collect()
+        })
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut sections = input.split("\n\n");
+
+        //Parse seeds
+        let line = sections.next().unwrap_or(input);
+        let seeds_str = parse::strip_prefix(input, line, "seeds:")?;
+        let seeds = parse::numbers(input, seeds_str)?;
+        if seeds.is_empty() {
+            return Err(ParseError::expected("seed numbers", input, seeds_str));
+        }
+        // Part 2 reads them as the starts and lengths of ranges
+        if seeds.len() % 2 == 1 {
+            return Err(ParseError::expected("seed numbers in pairs", input, seeds_str));
+        }
+
+        // Parse mappings
+        let mappings = sections
+            .map(|section| Mapping::from_str(section).map_err(|err| err.within(input, section)))
+            .collect::<Result<Vec<Mapping>, _>>()?;
+
+        Ok(Almanac {
+            seeds,
+            mappings
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn mapping(ranges: &[(u64, u64, u64)]) -> Mapping {
+        Mapping {
+            source: "a".to_string(),
+            destination: "b".to_string(),
+            ranges: ranges.iter().map(|&(dst, src, len)| Range { src, dst, len }).collect()
+        }
+    }
+
+    #[test]
+    fn test_map_interval() {
+        let mapping = mapping(&[(50, 98, 2), (52, 50, 48)]);
+
+        assert_eq!(mapping.map_interval(79..93), vec![81..95]);
+        assert_eq!(mapping.map_interval(40..60), [52..62, 40..50]);
+        assert_eq!(mapping.map_interval(95..105), [50..52, 97..100, 100..105]);
+    }
+
+    #[test]
+    fn test_overlapping_ranges() {
+        // The first range wins where they overlap
+        let mapping = mapping(&[(100, 10, 10), (200, 15, 10)]);
+
+        assert_eq!(mapping.map_interval(10..25), [100..110, 205..210]);
+        assert_eq!(mapping.map(17), 107);
+    }
+
+    #[test]
+    fn test_compose() {
+        let first = mapping(&[(50, 98, 2), (52, 50, 48)]);
+        let second = mapping(&[(0, 15, 37), (37, 52, 2), (39, 0, 15)]);
+        let composed = first.compose(&second);
+
+        for seed in [0, 14, 15, 49, 50, 51, 79, 97, 98, 99, 100, u64::MAX - 1, u64::MAX] {
+            assert_eq!(composed.map(seed), second.map(first.map(seed)), "seed {}", seed);
+        }
+    }
+
+    const EXAMPLE: &str = "seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+
+    #[test]
+    fn test_chain() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+
+        let chain = almanac.chain("seed", "location").unwrap();
+        assert_eq!(chain.mappings.len(), 7);
+        assert_eq!(chain.map(79), 82);
+        assert_eq!(chain.compose().map(79), 82);
+        assert_eq!(chain.compose().destination, "location");
+
+        let chain = almanac.chain("soil", "water").unwrap();
+        assert_eq!(chain.map(81), 81);
+        assert_eq!(chain.map(53), 27);
+
+        assert_eq!(almanac.chain("light", "light").unwrap().map(7), 7);
+        assert!(almanac.chain("location", "seed").is_none());
+        assert!(almanac.chain("seed", "moon").is_none());
+        assert!(almanac.chain("moon", "moon").is_none());
+    }
+
+    #[test]
+    fn test_chain_without_seeds() {
+        let almanac = "seeds: 1 1\n\nfruit-to-soil map:\n10 0 5\n\nsoil-to-water map:\n0 10 2"
+            .parse::<Almanac>().unwrap();
+        assert_eq!(almanac.categories().collect::<Vec<_>>(), ["fruit", "soil", "water"]);
+
+        let chain = almanac.chain("fruit", "water").unwrap();
+        assert_eq!(chain.map(1), 1);
+        assert_eq!(chain.map(4), 14);
+        assert!(almanac.chain("seed", "water").is_none());
+    }
+
+    #[test]
+    fn test_preimage() {
+        let almanac = EXAMPLE.parse::<Almanac>().unwrap();
+        let chain = almanac.chain("seed", "location").unwrap();
+
+        // The lowest location of part 2 comes from seed 82
+        let seeds = chain.preimage(46..47);
+        assert_eq!(seeds, vec![82..83]);
+        assert_eq!(chain.map(82), 46);
+
+        let mapping = mapping(&[(50, 98, 2), (52, 50, 48)]);
+        assert_eq!(mapping.preimage(50..53), [98..100, 50..51]);
+        // Both 50 and 98 map onto 50, while 100 maps onto itself
+        assert_eq!(mapping.preimage(99..101), [97..98, 100..101]);
+    }
+
+    #[test]
+    fn test_wide_values() {
+        let input = "seeds: 5000000000 10
+
+seed-to-soil map:
+18446744073709551000 5000000000 100
+
+soil-to-location map:
+0 18446744073709551005 3";
+        let almanac = input.parse::<Almanac>().unwrap();
+
+        assert_eq!(almanac.location(5_000_000_000), 18_446_744_073_709_551_000);
+        assert_eq!(almanac.location(5_000_000_007), 2);
+        assert_eq!(part2::solve(&almanac).unwrap(), 0);
+        // Seed 0 isn't in any range, and neither is the seed that would have become soil 18446744073709551005
+        let seeds = almanac.chain("seed", "location").unwrap().preimage(0..1);
+        assert_eq!(seeds, [5_000_000_005..5_000_000_006, 18_446_744_073_709_551_005..18_446_744_073_709_551_006, 0..1]);
+    }
+
+    #[test]
+    fn test_overflow() {
+        for range in ["18446744073709551615 0 2", "0 18446744073709551000 1000"] {
+            let input = format!("seeds: 1 1\n\nseed-to-soil map:\n{}", range);
+            let err = input.parse::<Almanac>().unwrap_err();
+            assert!(matches!(err, ParseError::Invalid { .. }), "{}", range);
+        }
+    }
+
+    #[test]
+    fn test_no_seeds() {
+        let err = "seeds:\n\nseed-to-soil map:\n1 2 3".parse::<Almanac>().unwrap_err();
+        assert_eq!(err.to_string(), "Expected seed numbers at line 1, column 7");
+
+        let err = "seeds: 1 2 3\n\nseed-to-soil map:\n1 2 3".parse::<Almanac>().unwrap_err();
+        assert_eq!(err.to_string(), "Expected seed numbers in pairs at line 1, column 7");
+    }
+
+    fn ranges() -> impl Strategy<Value=Vec<(u64, u64, u64)>> {
+        prop::collection::vec((0..300u64, 0..300u64, 1..50u64), 0..5)
+    }
+
+    proptest! {
+        #[test]
+        fn prop_map_interval(ranges in ranges(), start in 0..350u64, len in 1..60u64) {
+            let mapping = mapping(&ranges);
+            let mut mapped = mapping.map_interval(start..start + len).into_iter()
+                .flatten()
+                .collect::<Vec<_>>();
+            let mut expected = (start..start + len)
+                .map(|value| mapping.map(value))
+                .collect::<Vec<_>>();
+
+            mapped.sort();
+            expected.sort();
+            prop_assert_eq!(mapped, expected);
+        }
+
+        #[test]
+        fn prop_preimage(ranges in ranges(), start in 0..350u64, len in 1..60u64) {
+            let mapping = mapping(&ranges);
+            let target = start..start + len;
+            let mut preimage = mapping.preimage(target.clone()).into_iter()
+                .flatten()
+                .filter(|&value| value < 400)
+                .collect::<Vec<_>>();
+            let expected = (0..400)
+                .filter(|&value| target.contains(&(mapping.map(value))))
+                .collect::<Vec<_>>();
+
+            preimage.sort();
+            prop_assert_eq!(preimage, expected);
+        }
+
+        #[test]
+        fn prop_compose(first in ranges(), second in ranges()) {
+            let (first, second) = (mapping(&first), mapping(&second));
+            let composed = first.compose(&second);
+
+            for value in 0..400 {
+                prop_assert_eq!(composed.map(value), second.map(first.map(value)));
+            }
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::Almanac;
+
+pub fn solve(almanac: &Almanac) -> u64 {
+    almanac.seeds.iter()
+        .map(|seed| almanac.location(*seed))
+        .min()
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use crate::Day5;
+    use super::*;
+    #[test]
+    fn test_1() {
+        let input ="seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let almanac = Day5::parse(input).unwrap();
+
+        let locations = almanac.seeds.iter()
+            .map(|seed| almanac.location(*seed))
+            .collect::<Vec<u64>>();
+
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+        assert_eq!(solve(&almanac), 35);
+
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::almanac(5, 10, 7, 30, u32::MAX as u64);
+        assert!(Day5::solve_part1(&puzzle.input).is_ok());
+        assert!(Day5::solve_part2(&puzzle.input).is_ok());
+    }
+}
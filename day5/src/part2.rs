@@ -0,0 +1,122 @@
+use itertools::Itertools;
+use miette::miette;
+use crate::Almanac;
+
+fn seed_ranges(almanac: &Almanac) -> impl Iterator<Item = (u64, u64)> + '_ {
+    almanac.seeds.iter().copied().tuples()
+}
+
+pub fn solve(almanac: &Almanac) -> miette::Result<u64> {
+    let seed_intervals = seed_ranges(almanac)
+        .map(|(seed_num, len)| {
+            let end = seed_num.checked_add(len)
+                .ok_or_else(|| miette!("The seed range {} {} doesn't fit in a u64", seed_num, len))?;
+            Ok(seed_num..end)
+        })
+        .collect::<miette::Result<Vec<_>>>()?;
+
+    seed_intervals.into_iter()
+        .flat_map(|seeds| almanac.location_intervals(seeds))
+        .filter(|interval| !interval.is_empty())
+        .map(|interval| interval.start)
+        .min()
+        .ok_or_else(|| miette!("Every seed range is empty"))
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use aoc2023::Solution;
+    use crate::Day5;
+    use super::*;
+
+    /// Small almanacs, with seed ranges short enough to map every seed one by one
+    fn almanac() -> impl Strategy<Value=String> {
+        let seeds = prop::collection::vec((0..200u64, 1..20u64), 1..4);
+        let range = (0..300u64, 0..300u64, 1..50u64);
+        let mappings = prop::collection::vec(prop::collection::vec(range, 1..5), 1..5);
+
+        (seeds, mappings).prop_map(|(seeds, mappings)| {
+            let seeds = seeds.iter().map(|(start, len)| format!("{} {}", start, len)).collect::<Vec<_>>();
+            let mappings = mappings.iter().enumerate()
+                .map(|(idx, ranges)| {
+                    let ranges = ranges.iter().map(|(dst, src, len)| format!("{} {} {}", dst, src, len)).collect::<Vec<_>>();
+                    format!("step{}-to-step{} map:\n{}", idx, idx + 1, ranges.join("\n"))
+                })
+                .collect::<Vec<_>>();
+            format!("seeds: {}\n\n{}", seeds.join(" "), mappings.join("\n\n"))
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matches_every_seed(input in almanac()) {
+            let almanac = Day5::parse(&input).unwrap();
+            let lowest = seed_ranges(&almanac)
+                .flat_map(|(seed_num, len)| seed_num..seed_num + len)
+                .map(|seed_n| almanac.location(seed_n))
+                .min();
+
+            prop_assert_eq!(solve(&almanac).ok(), lowest);
+        }
+    }
+    #[test]
+    fn test_1() {
+        let input ="seeds: 79 14 55 13
+
+seed-to-soil map:
+50 98 2
+52 50 48
+
+soil-to-fertilizer map:
+0 15 37
+37 52 2
+39 0 15
+
+fertilizer-to-water map:
+49 53 8
+0 11 42
+42 0 7
+57 7 4
+
+water-to-light map:
+88 18 7
+18 25 70
+
+light-to-temperature map:
+45 77 23
+81 45 19
+68 64 13
+
+temperature-to-humidity map:
+0 69 1
+1 0 69
+
+humidity-to-location map:
+60 56 37
+56 93 4";
+        let almanac = Day5::parse(input).unwrap();
+
+        let lowest = seed_ranges(&almanac)
+            .flat_map(|(seed_num, len)| seed_num..seed_num + len)
+            .map(|seed_n| almanac.location(seed_n))
+            .min();
+
+        assert_eq!(lowest, Some(46));
+        assert_eq!(solve(&almanac).unwrap(), 46);
+    }
+
+    #[test]
+    fn test_empty_ranges() {
+        let err = Day5::solve_part2("seeds: 1 0 5 0\n\nseed-to-soil map:\n1 2 3").unwrap_err();
+        assert_eq!(err.to_string(), "Every seed range is empty");
+        assert!(Day5::solve_part2("seeds: 1\n\nseed-to-soil map:\n1 2 3").is_err());
+    }
+
+    #[test]
+    fn test_seed_range_overflow() {
+        let err = Day5::solve_part2("seeds: 18446744073709551610 6\n\nseed-to-soil map:\n1 2 3").unwrap_err();
+        assert_eq!(err.to_string(), "The seed range 18446744073709551610 6 doesn't fit in a u64");
+        assert!(Day5::solve_part2("seeds: 18446744073709551610 5\n\nseed-to-soil map:\n1 2 3").is_ok());
+    }
+}
\ No newline at end of file
@@ -1,6 +1,54 @@
+use std::iter::zip;
+use std::ops::RangeInclusive;
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
 pub type Num = i64;
 pub type FNum = f64;
 
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Vec<(Num, Num)>;
+    type Answer1 = Num;
+    type Answer2 = Num;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse_races(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(races: &Self::Parsed<'_>) -> miette::Result<Num> {
+        part1::solve(races)
+    }
+
+    fn part2(races: &Self::Parsed<'_>) -> miette::Result<Num> {
+        part2::solve(races)
+    }
+}
+
+fn parse_numbers(input: &str, line: Option<&str>, prefix: &str) -> Result<Vec<Num>, ParseError> {
+    let line = line.ok_or_else(|| ParseError::expected_at_end(format!("a line starting with {:?}", prefix), input))?;
+    parse::numbers(input, parse::strip_prefix(input, line, prefix)?)
+}
+
+/// Parses the (time, record distance) pairs of all races
+pub fn parse_races(input: &str) -> Result<Vec<(Num, Num)>, ParseError> {
+    let mut lines = input.lines();
+
+    let times = parse_numbers(input, lines.next(), "Time:")?;
+    let distances = parse_numbers(input, lines.next(), "Distance:")?;
+
+    if times.len() != distances.len() {
+        return Err(ParseError::expected(format!("{} distances", times.len()), input, input.lines().nth(1).unwrap_or(input)));
+    }
+
+    Ok(zip(times, distances).collect())
+}
+
 pub fn evaluate(hold_len: Num, total_len: Num) -> Num {
     -hold_len * (hold_len - total_len)
 }
@@ -78,33 +126,433 @@ pub fn search(duration: Num, record_distance: Num) -> (Num, Num) {
     (left, right)
 }
 
+/// The first and last winning hold times, `(1, 0)` when the record can't be beaten
 pub fn calculate(duration: Num, record_distance: Num) -> (Num, Num) {
+    let wins = |hold: Num| evaluate(hold, duration) > record_distance;
+
+    // Holding for half the race goes furthest, if that doesn't win nothing does
+    if duration < 0 || !wins(duration / 2) {
+        return (1, 0);
+    }
+
     let a = 1.0;
     let b = duration as FNum;
     let c = record_distance as FNum;
 
-    let mut left_f = -(-b + (b * b - 4.0 * a * c).sqrt()) / 2.0 * a;
-    let mut right_f = -(-b - (b * b - 4.0 * a * c).sqrt()) / 2.0 * a;
+    let left_f = -(-b + (b * b - 4.0 * a * c).sqrt()) / 2.0 * a;
+    let right_f = -(-b - (b * b - 4.0 * a * c).sqrt()) / 2.0 * a;
 
-    // To prevent rounding issues on "whole" numbers
-    if left_f.fract() == 0.0 {
-        left_f += 1e-32;
+    // The float roots are only close, step to the exact first and last winning hold times.
+    // A root that's a whole number is a tie with the record, which doesn't win.
+    // The steps stay within the race, and stop at the half way hold at the latest.
+    let mut left = (left_f.ceil() as Num).clamp(0, duration / 2);
+    while !wins(left) {
+        left += 1;
     }
-    if right_f.fract() == 0.0 {
-        right_f -= 1e-32;
+    while left > 0 && wins(left - 1) {
+        left -= 1;
     }
 
-    let left_ceil = left_f.ceil() as Num;
-    let right_floor = right_f.floor() as Num;
+    let mut right = (right_f.floor() as Num).clamp(duration / 2, duration);
+    while !wins(right) {
+        right -= 1;
+    }
+    while right < duration && wins(right + 1) {
+        right += 1;
+    }
 
-    (left_ceil, right_floor)
+    (left, right)
 }
 
+/// `x * x` as a 256 bit number, high half first
+fn square(x: u128) -> (u128, u128) {
+    let (hi, lo) = (x >> 64, x & u64::MAX as u128);
+    let cross = hi * lo;
+    let (low, carry) = (lo * lo).overflowing_add(cross << 65);
+
+    (hi * hi + (cross >> 63) + carry as u128, low)
+}
+
+/// The largest `x` with `x * x <= n`, for a 256 bit `n`, high half first
+fn isqrt(n: (u128, u128)) -> u128 {
+    (0..128).rev().fold(0, |root, bit| {
+        let candidate = root | 1 << bit;
+        if square(candidate) <= n { candidate } else { root }
+    })
+}
+
+/// Every hold time beating the record, found exactly with integer arithmetic.
+/// Empty when the record can't be beaten.
+///
+/// Holding for `h` wins when `h * (duration - h) > record`, or `(2h - duration)^2 < duration^2 - 4 * record`.
+/// The discriminant needs up to 256 bits, so it's kept as a pair of halves.
+pub fn winning_holds(duration: u128, record: u128) -> RangeInclusive<u128> {
+    let (hi, lo) = square(duration);
+    let (record_hi, record_lo) = (record >> 126, record << 2);
+    if (hi, lo) <= (record_hi, record_lo) {
+        return RangeInclusive::new(1, 0);
+    }
+
+    let (lo, borrow) = lo.overflowing_sub(record_lo);
+    let discriminant = (hi - record_hi - borrow as u128, lo);
+
+    // A perfect square is a hold time tying with the record, which doesn't win
+    let mut spread = isqrt(discriminant);
+    if square(spread) == discriminant {
+        spread -= 1;
+    }
+
+    // `2h - duration` has the same parity as `duration`
+    if (spread ^ duration) & 1 == 1 {
+        if spread == 0 {
+            return RangeInclusive::new(1, 0);
+        }
+        spread -= 1;
+    }
+
+    let left = (duration - spread) / 2;
+    left..=left + spread
+}
+
+/// How many hold times beat the record, none in a race of negative length and all of them for a negative record
+pub fn count_wins(duration: Num, record_distance: Num) -> u128 {
+    let Ok(duration) = u128::try_from(duration) else {
+        return 0;
+    };
+    let Ok(record) = u128::try_from(record_distance) else {
+        return duration + 1;
+    };
+
+    let holds = winning_holds(duration, record);
+    if holds.is_empty() { 0 } else { holds.end() - holds.start() + 1 }
+}
+
+/// The first value in `lo..=hi` where `pred` holds, `pred` must only ever switch from false to true
+fn first_where(mut lo: u64, mut hi: u64, mut pred: impl FnMut(u64) -> bool) -> Option<u64> {
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Some(lo)
+}
+
+/// How a boat moves: holding the button builds up speed, which may be capped,
+/// and once released drag may slow the boat down every millisecond until it stops
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RaceModel {
+    /// Speed gained for every millisecond the button is held
+    pub gain: u64,
+    pub max_speed: Option<u64>,
+    /// Speed lost for every millisecond travelled
+    pub drag: Option<u64>,
+}
+
+impl RaceModel {
+    /// The boats of the puzzle, which [`evaluate`] describes
+    pub const TOY_BOAT: RaceModel = RaceModel { gain: 1, max_speed: None, drag: None };
+
+    pub fn speed(&self, hold: u64) -> u64 {
+        let speed = self.gain.saturating_mul(hold);
+        self.max_speed.map_or(speed, |max| speed.min(max))
+    }
+
+    /// How far the boat gets when the button is held for `hold` out of `duration` milliseconds,
+    /// holding for the whole race or longer goes nowhere
+    pub fn distance(&self, hold: u64, duration: u64) -> u128 {
+        if hold >= duration {
+            return 0;
+        }
+        let speed = self.speed(hold) as u128;
+        let travel = (duration - hold) as u128;
+
+        match self.drag.filter(|&drag| drag > 0) {
+            None => speed * travel,
+            Some(drag) => {
+                // Moves at speed, speed - drag, speed - 2 * drag and so on
+                let drag = drag as u128;
+                let moving = travel.min(speed.div_ceil(drag));
+                moving * speed - drag * moving.saturating_sub(1) * moving / 2
+            }
+        }
+    }
+
+    /// Every hold time beating `record`, as sorted and disjoint intervals
+    pub fn winning_holds(&self, duration: u64, record: u128) -> Vec<RangeInclusive<u64>> {
+        let distance = |hold| self.distance(hold, duration);
+
+        // Holding longer only makes a boat that stops before the end go further,
+        // once it keeps moving until the end the distance is concave on either side of the speed cap
+        let still_moving = match self.drag.filter(|&drag| drag > 0) {
+            None => 0,
+            Some(drag) => first_where(0, duration, |hold| self.speed(hold).div_ceil(drag) >= duration - hold).unwrap(),
+        };
+        let capped = self.max_speed
+            .and_then(|max| first_where(0, duration, |hold| self.gain.saturating_mul(hold) >= max));
+
+        let mut intervals = Vec::new();
+
+        if still_moving > 0 {
+            intervals.extend(first_where(0, still_moving - 1, |hold| distance(hold) > record).map(|left| left..=still_moving - 1));
+        }
+
+        // Inclusive bounds, so a race lasting u64::MAX doesn't overflow
+        let pieces = match capped {
+            None => [Some((still_moving, duration)), None],
+            Some(capped) => [capped.checked_sub(1).map(|hi| (still_moving, hi)), Some((still_moving.max(capped), duration))],
+        };
+        for (lo, hi) in pieces.into_iter().flatten() {
+            if lo > hi {
+                continue;
+            }
+
+            let peak = first_where(lo, hi, |hold| hold == hi || distance(hold + 1) <= distance(hold)).unwrap();
+            if distance(peak) <= record {
+                continue;
+            }
+
+            let left = first_where(lo, peak, |hold| distance(hold) > record).unwrap();
+            let right = first_where(peak, hi, |hold| distance(hold) <= record).map_or(hi, |hold| hold - 1);
+            intervals.push(left..=right);
+        }
+
+        intervals.into_iter().fold(Vec::new(), |mut merged: Vec<RangeInclusive<u64>>, interval| {
+            match merged.last_mut() {
+                Some(last) if *interval.start() <= last.end().saturating_add(1) => *last = *last.start()..=*interval.end().max(last.end()),
+                _ => merged.push(interval),
+            }
+            merged
+        })
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
     use super::*;
 
+    /// The first and last winning hold times, trying every one, `(1, 0)` if there are none
+    fn brute_force(duration: Num, record_distance: Num) -> (Num, Num) {
+        let wins = (0..=duration)
+            .filter(|&hold| evaluate(hold, duration) > record_distance)
+            .collect::<Vec<_>>();
+        match wins[..] {
+            [] => (1, 0),
+            _ => (wins[0], wins[wins.len() - 1]),
+        }
+    }
+
+    /// A race with a record that can be beaten, so there is at least one winning hold time
+    fn race(max_duration: Num) -> impl Strategy<Value=(Num, Num)> {
+        (2..=max_duration).prop_flat_map(|duration| {
+            let best = evaluate(duration / 2, duration);
+            (Just(duration), 0..best)
+        })
+    }
+
+    /// A race with a record that sometimes ties or beats the best distance, or any race at all
+    fn any_race(max_duration: Num) -> impl Strategy<Value=(Num, Num)> {
+        (0..=max_duration).prop_flat_map(|duration| {
+            let best = evaluate(duration / 2, duration);
+            (Just(duration), 0..best + best / 4 + 2)
+        })
+    }
+
+    /// Whether holding for `hold` beats `record`, a distance too large for a `u128` beats any record
+    fn wins(duration: u128, record: u128, hold: u128) -> bool {
+        hold.checked_mul(duration - hold).is_none_or(|distance| distance > record)
+    }
+
+    /// The exact solver's range in the same shape as [`search`]
+    fn exact(duration: Num, record_distance: Num) -> (Num, Num) {
+        let holds = winning_holds(duration as u128, record_distance as u128);
+        (*holds.start() as Num, *holds.end() as Num)
+    }
+
+    #[test]
+    fn test_winning_holds() {
+        assert_eq!(winning_holds(7, 9), 2..=5);
+        assert_eq!(winning_holds(15, 40), 4..=11);
+        assert_eq!(winning_holds(30, 200), 11..=19);
+        assert_eq!(winning_holds(71530, 940200), 14..=71516);
+
+        // Perfect square discriminants, where the edges tie with the record
+        assert_eq!(winning_holds(10, 16), 3..=7);
+        assert_eq!(winning_holds(10, 24), 5..=5);
+        assert_eq!(winning_holds(11, 28), 5..=6);
+
+        // Records that can't be beaten
+        assert!(winning_holds(10, 25).is_empty());
+        assert!(winning_holds(11, 30).is_empty());
+        assert!(winning_holds(0, 0).is_empty());
+        assert!(winning_holds(1, 0).is_empty());
+        assert_eq!(winning_holds(2, 0), 1..=1);
+    }
+
+    #[test]
+    fn test_winning_holds_wide() {
+        assert_eq!(winning_holds(u128::MAX, 0), 1..=u128::MAX - 1);
+        assert_eq!(winning_holds(u128::MAX, u128::MAX), 2..=u128::MAX - 2);
+        assert_eq!(winning_holds(1 << 65, 0), 1..=(1 << 65) - 1);
+
+        // 2^64 * 2^64 = 2^128 is one past the largest record, while (2^64 - 1) * (2^64 + 1) ties it
+        assert_eq!(winning_holds(1 << 65, u128::MAX), 1 << 64..=1 << 64);
+        assert!(winning_holds(1 << 64, u128::MAX).is_empty());
+    }
+
+    #[test]
+    fn test_count_wins() {
+        assert_eq!(count_wins(7, 9), 4);
+        assert_eq!(count_wins(10, 24), 1);
+        assert_eq!(count_wins(10, 21), 3);
+        assert_eq!(count_wins(10, 25), 0);
+        assert_eq!(count_wins(10, -1), 11);
+        assert_eq!(count_wins(-3, 0), 0);
+    }
+
+    /// Every winning hold time of `model`, trying each one
+    fn brute_force_model(model: &RaceModel, duration: u64, record: u128) -> Vec<u64> {
+        (0..=duration)
+            .filter(|&hold| model.distance(hold, duration) > record)
+            .collect()
+    }
+
+    #[test]
+    fn test_race_model() {
+        assert_eq!(RaceModel::TOY_BOAT.winning_holds(30, 200), [11..=19]);
+        assert_eq!(RaceModel::TOY_BOAT.winning_holds(10, 25), []);
+
+        // Capped at 4, so holding longer than 2 only wastes time
+        let capped = RaceModel { gain: 2, max_speed: Some(4), drag: None };
+        assert_eq!(capped.distance(2, 10), 32);
+        assert_eq!(capped.distance(3, 10), 28);
+        assert_eq!(capped.winning_holds(10, 27), [2..=3]);
+
+        // Drag of 3 slows a boat at speed 10 to 7, 4 and 1
+        let dragged = RaceModel { gain: 2, max_speed: None, drag: Some(3) };
+        assert_eq!(dragged.distance(5, 20), 10 + 7 + 4 + 1);
+        assert_eq!(dragged.distance(5, 7), 10 + 7);
+        assert_eq!(dragged.winning_holds(20, 21), [5..=19]);
+    }
+
+    #[test]
+    fn test_race_model_plateau() {
+        // The drag stops the capped boat after a single millisecond, so every hold past the cap goes as far
+        let model = RaceModel { gain: 1, max_speed: Some(3), drag: Some(3) };
+        let distances = (0..=8).map(|hold| model.distance(hold, 8)).collect::<Vec<_>>();
+        assert_eq!(distances, [0, 1, 2, 3, 3, 3, 3, 3, 0]);
+        assert_eq!(model.winning_holds(8, 2), [3..=7]);
+        assert_eq!(model.winning_holds(8, 0), [1..=7]);
+        assert_eq!(model.winning_holds(8, 3), []);
+    }
+
+    #[test]
+    fn test_race_model_longest_race() {
+        assert_eq!(RaceModel::TOY_BOAT.distance(9, 8), 0);
+        assert_eq!(RaceModel::TOY_BOAT.winning_holds(u64::MAX, 0), [1..=u64::MAX - 1]);
+
+        let capped = RaceModel { gain: 1, max_speed: Some(10), drag: None };
+        assert_eq!(capped.winning_holds(u64::MAX, 0), [1..=u64::MAX - 1]);
+        assert_eq!(capped.winning_holds(u64::MAX, 10 * (u64::MAX - 11) as u128), [10..=10]);
+
+        let dragged = RaceModel { gain: 1, max_speed: Some(10), drag: Some(1) };
+        assert_eq!(dragged.winning_holds(u64::MAX, 54), [10..=u64::MAX - 10]);
+    }
+
+    fn race_model() -> impl Strategy<Value=RaceModel> {
+        (0..6u64, prop::option::of(0..40u64), prop::option::of(0..8u64))
+            .prop_map(|(gain, max_speed, drag)| RaceModel { gain, max_speed, drag })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_toy_boat_matches_exact(duration in 0..1_000_000u64, record in 0..1_000_000_000u64) {
+            let holds = winning_holds(duration as u128, record as u128);
+            let expected = if holds.is_empty() { vec![] } else { vec![*holds.start() as u64..=*holds.end() as u64] };
+            prop_assert_eq!(RaceModel::TOY_BOAT.winning_holds(duration, record as u128), expected);
+        }
+
+        #[test]
+        fn prop_race_model_matches_brute_force(model in race_model(), duration in 0..80u64, record in 0..600u128) {
+            let holds = model.winning_holds(duration, record).into_iter().flatten().collect::<Vec<_>>();
+            prop_assert_eq!(holds, brute_force_model(&model, duration, record));
+        }
+
+        #[test]
+        fn prop_exact_matches_search((duration, record) in race(100_000_000)) {
+            prop_assert_eq!(exact(duration, record), search(duration, record));
+        }
+
+        /// Every record some hold time ties with makes the discriminant a perfect square
+        #[test]
+        fn prop_exact_tied_record(duration in 2..100_000_000 as Num, hold in 0..50_000_000 as Num) {
+            let hold = hold % (duration / 2).max(1);
+            let record = evaluate(hold, duration);
+            prop_assume!(record < evaluate(duration / 2, duration));
+            prop_assert_eq!(exact(duration, record), search(duration, record));
+        }
+
+        #[test]
+        fn prop_exact_edges(duration in any::<u128>(), record in any::<u128>()) {
+            let holds = winning_holds(duration, record);
+            if holds.is_empty() {
+                prop_assert!(!wins(duration, record, duration / 2));
+                prop_assert!(!wins(duration, record, duration - duration / 2));
+            } else {
+                let (left, right) = (*holds.start(), *holds.end());
+                prop_assert!(wins(duration, record, left) && wins(duration, record, right));
+                prop_assert!(left == 0 || !wins(duration, record, left - 1));
+                prop_assert!(right == duration || !wins(duration, record, right + 1));
+            }
+        }
+
+        #[test]
+        fn prop_search_matches_brute_force((duration, record) in race(2_000)) {
+            prop_assert_eq!(search(duration, record), brute_force(duration, record));
+        }
+
+        #[test]
+        fn prop_calculate_matches_brute_force((duration, record) in any_race(2_000)) {
+            prop_assert_eq!(calculate(duration, record), brute_force(duration, record));
+        }
+
+        #[test]
+        fn prop_count_wins_matches_brute_force((duration, record) in any_race(2_000)) {
+            let (low, high) = brute_force(duration, record);
+            prop_assert_eq!(count_wins(duration, record), (high - low + 1) as u128);
+        }
+
+        /// The count only drops the holds tying with the record
+        #[test]
+        fn prop_count_wins_tied_record(duration in 0..100_000_000 as Num, hold in 0..50_000_000 as Num) {
+            let hold = hold % (duration / 2 + 1);
+            let record = evaluate(hold, duration);
+            let ties = if duration == 2 * hold { 1 } else { 2 };
+            prop_assert_eq!(count_wins(duration, record) + ties, count_wins(duration, record - 1));
+        }
+
+        #[test]
+        fn prop_calculate_matches_search((duration, record) in race(100_000_000)) {
+            prop_assert_eq!(calculate(duration, record), search(duration, record));
+        }
+
+        /// Records right at a winning distance are where the rounding nudges matter
+        #[test]
+        fn prop_exact_record(duration in 2..100_000_000 as Num, hold in 1..50_000_000 as Num) {
+            let hold = hold % (duration / 2).max(1);
+            let record = evaluate(hold, duration);
+            prop_assume!(record < evaluate(duration / 2, duration));
+            prop_assert_eq!(calculate(duration, record), search(duration, record));
+        }
+    }
+
     #[test]
     fn test_bounds_even() {
         let (left_bounds, right_bounds) = get_bounds(10);
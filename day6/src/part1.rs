@@ -0,0 +1,56 @@
+use miette::miette;
+use crate::*;
+
+pub fn solve(races: &[(Num, Num)]) -> miette::Result<Num> {
+    races.iter()
+        .try_fold(1, |product: u128, race| product.checked_mul(count_wins(race.0, race.1)))
+        .and_then(|product| Num::try_from(product).ok())
+        .ok_or_else(|| miette!("The number of ways to win doesn't fit in an i64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day6;
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+        let races = parse_races(input).unwrap();
+
+        let score = races.iter().map(|race| {
+            let (low, high) = search(race.0, race.1);
+            high - low + 1
+        }).product::<Num>();
+
+        assert_eq!(score, 288);
+        assert_eq!(solve(&races).unwrap(), 288);
+    }
+
+    #[test]
+    fn test_unbeatable_and_tied_records() {
+        // 5 * 5 = 25 is the furthest a race of 10 goes, while 3 * 7 ties with 21
+        assert_eq!(Day6::solve_part1("Time: 10 7\nDistance: 25 9").unwrap(), 0);
+        assert_eq!(Day6::solve_part1("Time: 10 10\nDistance: 24 21").unwrap(), 3);
+        assert_eq!(Day6::solve_part2("Time: 1 0\nDistance: 2 5").unwrap(), 0);
+        assert!(Day6::solve_part1("Time: 4000000000 4000000000 4000000000\nDistance: 0 0 0").is_err());
+    }
+
+    #[test]
+    fn test_generated() {
+        let mut unwinnable = 0;
+        for seed in 0..20 {
+            let puzzle = gen::races(seed, 4, 100);
+            assert_eq!(Day6::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+            assert!(Day6::solve_part2(&puzzle.input).is_ok());
+            unwinnable += (puzzle.part1 == Some(0)) as usize;
+        }
+
+        // Some races with records that can't be beaten, which leave no way to win at all
+        assert!(unwinnable > 0);
+    }
+}
@@ -0,0 +1,42 @@
+use miette::miette;
+use crate::*;
+
+// The kerning was wrong: all the races are actually a single one with the digits concatenated.
+// `None` if there are no races, or the joined numbers don't fit.
+fn join_race(races: &[(Num, Num)]) -> Option<(Num, Num)> {
+    let join = |nums: Vec<String>| nums.concat().parse::<Num>().ok();
+
+    let time = join(races.iter().map(|race| race.0.to_string()).collect())?;
+    let distance = join(races.iter().map(|race| race.1.to_string()).collect())?;
+
+    Some((time, distance))
+}
+
+pub fn solve(races: &[(Num, Num)]) -> miette::Result<Num> {
+    let (time, distance) = join_race(races).ok_or_else(|| miette!("The races don't join into one race that fits in an i64"))?;
+    Num::try_from(count_wins(time, distance)).map_err(|_| miette!("The number of ways to win doesn't fit in an i64"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_example() {
+        let input = "Time:      7  15   30
+Distance:  9  40  200";
+
+        let (time, distance) = join_race(&parse_races(input).unwrap()).unwrap();
+        let (low, high) = search(time, distance);
+        let score = high - low + 1;
+
+        assert_eq!(score, 71503)
+    }
+
+    #[test]
+    fn test_join_overflow() {
+        let races = parse_races("Time: 99999999999 99999999999\nDistance: 1 1").unwrap();
+        assert!(solve(&races).is_err());
+        assert!(solve(&[]).is_err());
+    }
+}
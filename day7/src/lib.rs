@@ -3,12 +3,37 @@ use std::fmt::{Debug, Formatter};
 use std::hash::{Hash, Hasher};
 use std::str::FromStr;
 use itertools::Itertools;
-use miette::{miette, Report};
 use unicode_segmentation::UnicodeSegmentation;
-use crate::HandType::{FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair};
+use HandType::{FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair};
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Vec<Hand>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse::lines(input, Hand::from_str).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(hands: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part1::solve(hands))
+    }
+
+    fn part2(hands: &Self::Parsed<'_>) -> miette::Result<usize> {
+        Ok(part2::solve(hands))
+    }
+}
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug, Hash, Copy, Clone)]
-enum Card {
+pub enum Card {
     A,
     K,
     Q,
@@ -25,7 +50,7 @@ enum Card {
 }
 
 impl FromStr for Card {
-    type Err = Report;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
@@ -42,13 +67,13 @@ impl FromStr for Card {
             "4" => Ok(Card::N4),
             "3" => Ok(Card::N3),
             "2" => Ok(Card::N2),
-            x => Err(miette!("Unknown card: {}", x))
+            x => Err(ParseError::invalid("card", x, x))
         }
     }
 }
 
 #[derive(Ord, PartialOrd, Eq, PartialEq, Debug)]
-enum HandType {
+pub enum HandType {
     FiveOfAKind,
     FourOfAKind,
     FullHouse,
@@ -58,28 +83,25 @@ enum HandType {
     HighCard
 }
 
-struct Hand {
-    cards: [Card; 5],
-    bid: usize
+pub struct Hand {
+    pub cards: [Card; 5],
+    pub bid: usize
 }
 
 impl FromStr for Hand {
-    type Err = Report;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (mut cards, bid) = s.split_whitespace().collect_tuple::<(&str, &str)>()
-            .ok_or(miette!("Failed to split tuple: {}", s))
-            .and_then(|(a, b)| Ok((
-                a.graphemes(true)
-                    .map(|x| x.parse::<Card>())
-                    .collect::<Result<Vec<Card>, Self::Err>>()
-                    .map_err(|_| miette!("Failed to parse cards: {}", b))?,
-                b.parse::<usize>().map_err(|_| miette!("Failed to parse bid: {}", a))?
-            )))?;
+        let (cards_str, bid) = s.split_whitespace().collect_tuple::<(&str, &str)>()
+            .ok_or_else(|| ParseError::expected("cards and a bid", s, s))?;
+
+        let cards = cards_str.graphemes(true)
+            .map(|x| x.parse::<Card>().map_err(|err| err.within(s, x)))
+            .collect::<Result<Vec<Card>, Self::Err>>()?;
 
         Ok(Self {
-            cards: cards.try_into().map_err(|_| miette!("Failed to convert to array: {}", s))?,
-            bid
+            cards: cards.try_into().map_err(|_| ParseError::expected("5 cards", s, cards_str))?,
+            bid: parse::number(s, bid)?
         })
     }
 }
@@ -128,7 +150,7 @@ impl PartialEq<Self> for Hand {
 impl Eq for Hand { }
 
 impl Hand {
-    fn get_type(&self) -> HandType {
+    pub fn get_type(&self) -> HandType {
 
         let card_counts = self.cards.iter().copied().counts();
         let mut sorted_cards = self.cards;
@@ -152,68 +174,3 @@ impl Hand {
         }
     }
 }
-
-
-fn main() {
-    let input = include_str!("../input1.txt");
-
-    let mut hands = input.lines()
-        .map(Hand::from_str)
-        .collect::<Result<Vec<Hand>, _>>()
-        .unwrap();
-
-    hands.sort();
-
-    for (idx, hand) in hands.iter().rev().enumerate() {
-        println!("{:?} - {}", hand, idx + 1);
-    }
-
-    let result = hands.iter().rev().enumerate()
-        .fold(0, |acc, (idx, el)| acc + ((idx + 1) * el.bid));
-
-    println!("Result: {}", result);
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_input() {
-        let input = "32T3K 765
-T55J5 684
-KK677 28
-KTJJT 220
-QQQJA 483";
-
-        let mut hands = input.lines()
-            .map(Hand::from_str)
-            .collect::<Result<Vec<Hand>, _>>()
-            .unwrap();
-
-        hands.sort();
-
-        let result = hands.iter().rev().enumerate()
-            .fold(0, |acc, (idx, el)| acc + ((idx + 1) * el.bid));
-
-        assert_eq!(result, 6440);
-    }
-
-    #[test]
-    fn check_unique() {
-        let input = include_str!("../input1.txt");
-
-        let hands = input.lines()
-            .map(Hand::from_str)
-            .collect::<Result<Vec<Hand>, _>>()
-            .unwrap();
-
-        let n_hands = hands.iter().count();
-        let n_unique = hands.iter().unique().count();
-
-        println!("number of hands: {}", n_hands);
-        println!("unique hands: {}", n_unique);
-
-        assert_eq!(n_unique, n_hands);
-    }
-}
\ No newline at end of file
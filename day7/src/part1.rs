@@ -0,0 +1,53 @@
+use crate::Hand;
+
+pub fn solve(hands: &[Hand]) -> usize {
+    let mut hands = hands.iter().collect::<Vec<&Hand>>();
+
+    hands.sort();
+
+    hands.iter().rev().enumerate()
+        .fold(0, |acc, (idx, el)| acc + ((idx + 1) * el.bid))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::gen;
+    use aoc2023::Solution;
+    use itertools::Itertools;
+    use crate::Day7;
+
+    #[test]
+    fn test_input() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let result = Day7::solve_part1(input).unwrap();
+
+        assert_eq!(result, 6440);
+    }
+
+    #[test]
+    fn check_unique() {
+        let input = &aoc2023::input::load(7).unwrap();
+
+        let hands = Day7::parse(input).unwrap();
+
+        let n_hands = hands.len();
+        let n_unique = hands.iter().unique().count();
+
+        println!("number of hands: {}", n_hands);
+        println!("unique hands: {}", n_unique);
+
+        assert_eq!(n_unique, n_hands);
+    }
+
+    #[test]
+    fn test_generated() {
+        let puzzle = gen::camel_hands(7, 1000);
+        assert!(Day7::solve_part1(&puzzle.input).is_ok());
+        assert!(Day7::solve_part2(&puzzle.input).is_ok());
+    }
+}
@@ -0,0 +1,192 @@
+use std::cmp::Ordering;
+use itertools::Itertools;
+use crate::{Card, Hand, HandType};
+use HandType::{FiveOfAKind, FourOfAKind, FullHouse, HighCard, OnePair, ThreeOfAKind, TwoPair};
+
+// Jokers are wild when determining the hand type, but rank below every other card
+fn card_rank(card: Card) -> (bool, Card) {
+    (card == Card::J, card)
+}
+
+struct JokerHand<'a>(&'a Hand);
+
+impl Ord for JokerHand<'_> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let type_ord = self.get_type().cmp(&other.get_type());
+
+        if type_ord == Ordering::Equal {
+            for (s, o) in self.0.cards.iter().zip(&other.0.cards) {
+                let card_ord = card_rank(*s).cmp(&card_rank(*o));
+                if card_ord != Ordering::Equal {
+                    return card_ord;
+                }
+            }
+        }
+
+        type_ord
+    }
+}
+
+impl PartialOrd for JokerHand<'_> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq<Self> for JokerHand<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for JokerHand<'_> {}
+
+impl JokerHand<'_> {
+    fn get_type(&self) -> HandType {
+        let card_counts = self.0.cards.iter().copied().counts();
+
+        let mut cards = card_counts.iter()
+            .map(|(k, v)| (*k, *v))
+            .collect::<Vec<(Card, usize)>>();
+
+        cards.sort_by(|a, b| {
+            a.1.cmp(&b.1)
+        });
+
+        let cc = cards.iter().map(|(_, b)| *b).rev().collect::<Vec<_>>();
+
+        let n_j = *card_counts.get(&Card::J).unwrap_or(&0usize);
+
+        match cards.len() {
+            1 => FiveOfAKind,
+            2 => match n_j {
+                0 => match cc.as_slice() {
+                    [4, 1] => FourOfAKind,
+                    [3, 2] => FullHouse,
+                    _ => panic!("This should be impossible")
+                }
+                _ => FiveOfAKind,
+            }
+            3 => match n_j {
+                0 => match cc.as_slice() {
+                    [3, 1, 1] => ThreeOfAKind,
+                    [2, 2, 1] => TwoPair,
+                    _ => panic!("This should be impossible")
+                }
+                1 => match cc.as_slice() {
+                    [3, 1, 1] => FourOfAKind,
+                    [2, 2, 1] => FullHouse,
+                    _ => panic!("This should be impossible")
+                }
+                _ => match cc.as_slice() {
+                    [3, 1, 1] => FourOfAKind,
+                    [2, 2, 1] => FourOfAKind,
+                    _ => panic!("This should be impossible")
+                }
+            }
+            4 => match n_j {
+                0 => OnePair,
+                1 => ThreeOfAKind,
+                2 => ThreeOfAKind,
+                _ => panic!("This should be impossible")
+            }
+            5 => match n_j {
+                0 => HighCard,
+                1 => OnePair,
+                _ => panic!("This should be impossible")
+            }
+            _ => panic!("Can't have more than 5 cards")
+        }
+    }
+}
+
+pub fn solve(hands: &[Hand]) -> usize {
+    let mut hands = hands.iter().map(JokerHand).collect::<Vec<JokerHand>>();
+
+    hands.sort();
+
+    hands.iter().rev().enumerate()
+        .fold(0, |acc, (idx, el)| acc + ((idx + 1) * el.0.bid))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use itertools::Itertools;
+    use crate::Day7;
+
+    #[test]
+    fn test_input() {
+        let input = "32T3K 765
+T55J5 684
+KK677 28
+KTJJT 220
+QQQJA 483";
+
+        let result = Day7::solve_part2(input).unwrap();
+
+        assert_eq!(result, 5905);
+    }
+
+
+    #[test]
+    fn test_input_2() {
+        let input = "2345A 1
+Q2KJJ 13
+Q2Q2Q 19
+T3T3J 17
+T3Q33 11
+2345J 3
+J345A 2
+32T3K 5
+T55J5 29
+KK677 7
+KTJJT 34
+QQQJA 31
+JJJJJ 37
+JAAAA 43
+AAAAJ 59
+AAAAA 61
+2AAAA 23
+2JJJJ 53
+JJJJ2 41";
+
+        let result = Day7::solve_part2(input).unwrap();
+
+        assert_eq!(result, 6839);
+    }
+
+    #[test]
+    fn test_input_3() {
+        let input = "AAAAA 2
+22222 3
+AAAAK 5
+22223 7
+AAAKK 11
+22233 13
+AAAKQ 17
+22234 19
+AAKKQ 23
+22334 29
+AAKQJ 31
+22345 37
+AKQJT 41
+23456 43";
+
+        let result = Day7::solve_part2(input).unwrap();
+
+        assert_eq!(result, 1369);
+    }
+
+    #[test]
+    fn check_unique() {
+        let input = &aoc2023::input::load(7).unwrap();
+
+        let hands = Day7::parse(input).unwrap();
+
+        let n_hands = hands.len();
+        let n_unique = hands.iter().unique().count();
+
+        assert_eq!(n_unique, n_hands);
+    }
+}
\ No newline at end of file
@@ -0,0 +1,148 @@
+use std::collections::{BTreeMap, BTreeSet};
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = (Vec<StepDirection>, Graph);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse(input).map_err(|err| err.with_source(input))
+    }
+
+    fn part1((steps, graph): &Self::Parsed<'_>) -> miette::Result<usize> {
+        part1::solve(steps, graph)
+    }
+
+    fn part2((steps, graph): &Self::Parsed<'_>) -> miette::Result<usize> {
+        part2::solve(steps, graph)
+    }
+}
+
+pub struct Node {
+    left: Option<usize>,
+    right: Option<usize>,
+}
+
+#[derive(Copy, Clone)]
+pub enum StepDirection {
+    Left,
+    Right
+}
+
+pub struct Graph {
+    node_name_map: BTreeMap<String, usize>,
+    nodes: Vec<Node>
+}
+
+impl Graph {
+    fn new() -> Self {
+        Self {
+            node_name_map: BTreeMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    pub fn get_idx(&self, name: &str) -> Option<usize> {
+        self.node_name_map.get(name).copied()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.node_name_map.keys().map(String::as_str)
+    }
+
+    pub fn step(&self, from_idx: usize, step_direction: StepDirection) -> Option<usize> {
+        match self.nodes.get(from_idx) {
+            None => panic!("No node with idx: {}", from_idx),
+            Some(node) => match step_direction {
+                StepDirection::Left => node.left,
+                StepDirection::Right => node.right
+            }
+        }
+    }
+
+    fn add(&mut self, name: &str, left: &str, right: &str) -> usize {
+        let left_node_idx = *self.node_name_map.entry(left.to_string())
+            .or_insert_with(|| {
+                let n = Node {
+                    left: None,
+                    right: None
+                };
+                self.nodes.push(n);
+                self.nodes.len() - 1
+            });
+
+        let right_node_idx = *self.node_name_map.entry(right.to_string())
+            .or_insert_with(|| {
+                let n = Node {
+                    left: None,
+                    right: None
+                };
+                self.nodes.push(n);
+                self.nodes.len() - 1
+            });
+
+        let cur_node_idx = *self.node_name_map.entry(name.to_string())
+            .or_insert_with(|| {
+                let n = Node {
+                    left: Some(left_node_idx),
+                    right: Some(right_node_idx)
+                };
+                self.nodes.push(n);
+                self.nodes.len() - 1
+            });
+
+        // Make sure the current node is linked to its descendants
+        let cur_node = self.nodes.get_mut(cur_node_idx).unwrap();
+        cur_node.left.replace(left_node_idx);
+        cur_node.right.replace(right_node_idx);
+
+        cur_node_idx
+    }
+}
+
+fn parse_node(line: &str) -> Result<(&str, &str, &str), ParseError> {
+    let (from, targets) = parse::split_once(line, line, " = ")?;
+    let targets = parse::strip_prefix(line, targets, "(")?;
+    let targets = targets.strip_suffix(')')
+        .ok_or_else(|| ParseError::expected("\")\"", line, &targets[targets.len()..]))?;
+    let (left, right) = parse::split_once(line, targets, ", ")?;
+
+    Ok((from, left, right))
+}
+
+pub fn parse(input: &str) -> Result<(Vec<StepDirection>, Graph), ParseError> {
+    let (steps_line, nodes) = input.split_once("\n\n")
+        .ok_or_else(|| ParseError::expected_at_end("an empty line followed by the nodes", input))?;
+
+    let steps = steps_line.char_indices().map(|(idx, c)| match c {
+        'R' => Ok(StepDirection::Right),
+        'L' => Ok(StepDirection::Left),
+        _ => Err(ParseError::invalid("step direction", input, &steps_line[idx..idx + c.len_utf8()]))
+    }).collect::<Result<Vec<_>, _>>()?;
+    if steps.is_empty() {
+        return Err(ParseError::expected("a step direction", input, steps_line));
+    }
+
+    let nodes = parse::lines(nodes, parse_node).map_err(|err| err.within(input, nodes))?;
+
+    // Every node has to be defined, or the walks would step off the graph
+    let defined = nodes.iter().map(|&(from, _, _)| from).collect::<BTreeSet<_>>();
+    if let Some(&undefined) = nodes.iter().flat_map(|(_, left, right)| [left, right]).find(|node| !defined.contains(*node)) {
+        return Err(ParseError::invalid("node, it's never defined", input, undefined));
+    }
+
+    let mut graph = Graph::new();
+    for (from, left, right) in nodes {
+        graph.add(from, left, right);
+    }
+
+    Ok((steps, graph))
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeSet;
+use miette::miette;
+use crate::*;
+
+/// Steps from AAA to ZZZ, `None` if it goes round in circles without getting there
+fn walk_graph(steps: &[StepDirection], graph: &Graph, begin_idx: usize, end_idx: usize) -> Option<usize> {
+    let mut stepcount = 0;
+    let mut cur_idx = begin_idx;
+    let mut seen = BTreeSet::new();
+
+    while cur_idx != end_idx {
+        // Starting the steps over from a node they've started from before goes round the same circle again
+        if !seen.insert(cur_idx) {
+            return None;
+        }
+        for step in steps.iter().copied() {
+            stepcount += 1;
+            cur_idx = graph.step(cur_idx, step).unwrap();
+        }
+    }
+
+    Some(stepcount)
+}
+
+
+pub fn solve(steps: &[StepDirection], graph: &Graph) -> miette::Result<usize> {
+    let begin_idx = graph.get_idx("AAA").ok_or_else(|| miette!("There's no node AAA to start from"))?;
+    let end_idx = graph.get_idx("ZZZ").ok_or_else(|| miette!("There's no node ZZZ to get to"))?;
+
+    walk_graph(steps, graph, begin_idx, end_idx).ok_or_else(|| miette!("The steps never lead from AAA to ZZZ"))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "RL
+
+AAA = (BBB, CCC)
+BBB = (DDD, EEE)
+CCC = (ZZZ, GGG)
+DDD = (DDD, DDD)
+EEE = (EEE, EEE)
+GGG = (GGG, GGG)
+ZZZ = (ZZZ, ZZZ)";
+
+        let (steps, graph) = parse(input).unwrap();
+
+        let stepcount = solve(&steps, &graph).unwrap();
+
+        assert_eq!(stepcount, 2);
+    }
+
+    #[test]
+    fn test_input_2() {
+        let input = "LLR
+
+AAA = (BBB, BBB)
+BBB = (AAA, ZZZ)
+ZZZ = (ZZZ, ZZZ)";
+
+        let (steps, graph) = parse(input).unwrap();
+
+        let stepcount = solve(&steps, &graph).unwrap();
+
+        assert_eq!(stepcount, 6);
+    }
+
+    #[test]
+    fn test_malformed() {
+        assert!(parse("\n\nAAA = (AAA, AAA)").is_err());
+        assert_eq!(parse("L\n\nAAA = (BBB, ZZZ)\nZZZ = (ZZZ, ZZZ)").err().unwrap().to_string(),
+                   "Invalid node, it's never defined \"BBB\" at line 3, column 8");
+
+        let (steps, graph) = parse("L\n\nAAA = (BBB, ZZZ)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)").unwrap();
+        assert_eq!(solve(&steps, &graph).unwrap_err().to_string(), "The steps never lead from AAA to ZZZ");
+    }
+}
\ No newline at end of file
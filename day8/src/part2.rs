@@ -0,0 +1,125 @@
+use std::collections::BTreeSet;
+use miette::miette;
+use crate::*;
+
+fn find_positions<'a>(graph: &'a Graph, suffix: &str) -> BTreeSet<&'a str> {
+    graph.names()
+        .filter(|name| name.ends_with(suffix))
+        .collect()
+}
+
+/// Steps from `start_position` to any of the `end_positions`, `None` if it goes round in circles without getting there
+fn walk_graph(steps: &[StepDirection], graph: &Graph, start_position: &str, end_positions: &BTreeSet<&str>) -> Option<usize> {
+    let begin_idx = graph.get_idx(start_position).unwrap();
+    let end_idxes = end_positions.iter().map(|s| graph.get_idx(s).unwrap()).collect::<Vec<_>>();
+
+    let mut stepcount = 0;
+    let mut cur_idx = begin_idx;
+    let mut seen = BTreeSet::new();
+
+    while !end_idxes.contains(&cur_idx) {
+        if !seen.insert(cur_idx) {
+            return None;
+        }
+        for step in steps.iter().copied() {
+            stepcount += 1;
+            cur_idx = graph.step(cur_idx, step).unwrap();
+        }
+    }
+
+    Some(stepcount)
+}
+
+fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        (a, b) = (b, a % b)
+    }
+    a
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    (a * b) / gcd(a, b)
+}
+
+fn lcm_multiple(numbers: &[usize]) -> usize {
+    numbers.iter().fold(1, |acc, el| lcm(acc, *el))
+}
+
+pub fn solve(steps: &[StepDirection], graph: &Graph) -> miette::Result<usize> {
+    let start_positions = find_positions(graph, "A");
+    let end_positions = find_positions(graph, "Z");
+    if start_positions.is_empty() {
+        return Err(miette!("There are no nodes ending in A to start from"));
+    }
+
+    let stepcounts = start_positions.iter().map(|s| {
+        walk_graph(steps, graph, s, &end_positions).ok_or_else(|| miette!("The steps never lead from {} to a node ending in Z", s))
+    }).collect::<miette::Result<Vec<_>>>()?;
+
+    Ok(lcm_multiple(&stepcounts))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day8;
+    use super::*;
+
+    #[test]
+    fn test_input_1() {
+        let input = "LR
+
+11A = (11B, XXX)
+11B = (XXX, 11Z)
+11Z = (11B, XXX)
+22A = (22B, XXX)
+22B = (22C, 22C)
+22C = (22Z, 22Z)
+22Z = (22B, 22B)
+XXX = (XXX, XXX)";
+
+        let (steps, graph) = parse(input).unwrap();
+        let start_positions = find_positions(&graph, "A");
+        let end_positions = find_positions(&graph, "Z");
+
+        let stepcounts = start_positions.iter().map(|s| {
+            walk_graph(&steps, &graph, s, &end_positions).unwrap()
+        }).collect::<Vec<_>>();
+
+        let lcm_result = lcm_multiple(&stepcounts);
+
+        assert_eq!(lcm_result, 6);
+    }
+
+    #[test]
+    fn test_lcm(){
+        assert_eq!(lcm(2, 5), 10);
+        assert_eq!(lcm(10, 5), 10);
+        assert_eq!(lcm(8, 5), 40);
+    }
+
+    #[test]
+    fn test_lcm_multiple(){
+        assert_eq!(lcm_multiple(&[2, 5, 8]), 40);
+        assert_eq!(lcm_multiple(&[2, 5, 8, 10]), 40);
+        assert_eq!(lcm_multiple(&[2, 5, 8, 9]), 360);
+        assert_eq!(lcm_multiple(&[7]), 7);
+    }
+
+    #[test]
+    fn test_no_start() {
+        let (steps, graph) = parse("L\n\nBBB = (BBB, BBB)").unwrap();
+        assert_eq!(solve(&steps, &graph).unwrap_err().to_string(), "There are no nodes ending in A to start from");
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::network(seed, 17, 5);
+            assert_eq!(Day8::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+            assert_eq!(Day8::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
+}
@@ -1,9 +1,34 @@
+use aoc2023::error::ParseError;
+use aoc2023::parse;
+use aoc2023::Solution;
+
+pub mod part1;
+pub mod part2;
+
 pub type Num = i32;
 
-pub fn parse_line(input: &str) -> Vec<Num> {
-    input.split_whitespace()
-        .map(|x| x.parse().unwrap())
-        .collect()
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Vec<Num>>;
+    type Answer1 = Num;
+    type Answer2 = Num;
+
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>> {
+        parse::lines(input, parse_line).map_err(|err| err.with_source(input))
+    }
+
+    fn part1(histories: &Self::Parsed<'_>) -> miette::Result<Num> {
+        part1::solve(histories)
+    }
+
+    fn part2(histories: &Self::Parsed<'_>) -> miette::Result<Num> {
+        part2::solve(histories)
+    }
+}
+
+pub fn parse_line(input: &str) -> Result<Vec<Num>, ParseError> {
+    parse::numbers(input, input)
 }
 
 pub fn all_zeroes(input: &[Num]) -> bool {
@@ -23,7 +48,8 @@ pub fn calc_next(buf: &mut Vec<Num>) {
     buf.pop();
 }
 
-pub fn extrapolate(input: &[Num]) -> Num {
+/// The next value of the history, `None` if its differences run out before they're all zero
+pub fn extrapolate(input: &[Num]) -> Option<Num> {
     let mut last_nums = Vec::new();
     let mut cur_level = Vec::from(input);
 
@@ -33,8 +59,8 @@ pub fn extrapolate(input: &[Num]) -> Num {
     }
 
     if cur_level.is_empty() {
-        panic!("Invalid calculation")
+        return None;
     }
 
-    last_nums.iter().sum()
-}
\ No newline at end of file
+    Some(last_nums.iter().sum())
+}
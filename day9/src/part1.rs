@@ -1,20 +1,10 @@
-use std::time::Instant;
-use day9::*;
+use miette::miette;
+use crate::*;
 
-fn main() {
-    let input = include_str!("../input1.txt");
-
-    let start = Instant::now();
-
-    let result = input.lines()
-        .map(parse_line)
-        .map(|line| extrapolate(&line))
-        .sum::<Num>();
-
-    let duration = start.elapsed();
-
-    println!("Result: {}", result);
-    println!("Time: {:?}", duration);
+pub fn solve(histories: &[Vec<Num>]) -> miette::Result<Num> {
+    histories.iter()
+        .map(|line| extrapolate(line).ok_or_else(|| miette!("Can't extrapolate {:?}, its differences never get to all zeroes", line)))
+        .sum()
 }
 
 #[cfg(test)]
@@ -28,8 +18,8 @@ mod tests {
 10 13 16 21 30 45";
 
         let result = input.lines()
-            .map(parse_line)
-            .map(|line| extrapolate(&line))
+            .map(|line| parse_line(line).unwrap())
+            .map(|line| extrapolate(&line).unwrap())
             .sum::<Num>();
 
         assert_eq!(result, 114);
@@ -38,43 +28,43 @@ mod tests {
     #[test]
     fn test_input_1() {
         let input = "0 3 6 9 12 15";
-        let parsed = parse_line(input);
+        let parsed = parse_line(input).unwrap();
         let result = extrapolate(&parsed);
 
-        assert_eq!(result, 18);
+        assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_input_2() {
         let input = "1 3 6 10 15 21";
-        let parsed = parse_line(input);
+        let parsed = parse_line(input).unwrap();
         let result = extrapolate(&parsed);
 
-        assert_eq!(result, 28);
+        assert_eq!(result, Some(28));
     }
 
     #[test]
     fn test_input_3() {
         let input = "10 13 16 21 30 45";
-        let parsed = parse_line(input);
+        let parsed = parse_line(input).unwrap();
         let result = extrapolate(&parsed);
 
-        assert_eq!(result, 68);
+        assert_eq!(result, Some(68));
     }
 
     #[test]
     fn test_with_negative() {
         let input = "-6 -3 0 3 6 9 12 15";
-        let parsed = parse_line(input);
+        let parsed = parse_line(input).unwrap();
         let result = extrapolate(&parsed);
 
-        assert_eq!(result, 18);
+        assert_eq!(result, Some(18));
     }
 
     #[test]
     fn test_big() {
         let input = "1 6 8 2 -10 -6 67 305 879 2127 4775 10402 22342 47347 98546 200559 398101 770084 1452146 2671762 4801686";
-        let mut parsed = parse_line(input);
+        let mut parsed = parse_line(input).unwrap();
 
         // println!("{:?}", parsed);
         while !all_zeroes(&parsed) {
@@ -84,4 +74,13 @@ mod tests {
 
         assert!(!parsed.is_empty())
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_never_zero() {
+        assert_eq!(extrapolate(&[1, 2]), None);
+        assert_eq!(extrapolate(&[]), None);
+
+        let err = Day9::solve_part1("0 3 6\n1 2").unwrap_err();
+        assert_eq!(err.to_string(), "Can't extrapolate [1, 2], its differences never get to all zeroes");
+    }
+}
@@ -0,0 +1,56 @@
+use miette::miette;
+use crate::*;
+
+pub fn solve(histories: &[Vec<Num>]) -> miette::Result<Num> {
+    histories.iter()
+        .map(|line| {
+            let mut reversed = line.clone();
+            reversed.reverse();
+            extrapolate(&reversed).ok_or_else(|| miette!("Can't extrapolate {:?}, its differences never get to all zeroes", line))
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc2023::Solution;
+    use aoc2023::gen;
+    use crate::Day9;
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let input = "0 3 6 9 12 15
+1 3 6 10 15 21
+10 13 16 21 30 45";
+
+        let result = input.lines()
+            .map(|line| parse_line(line).unwrap())
+            .map(|mut line| {
+                line.reverse();
+                extrapolate(&line).unwrap()
+            })
+            .sum::<Num>();
+
+        assert_eq!(result, 2);
+    }
+
+    #[test]
+    fn test_input_1() {
+        let input = "10 13 16 21 30 45";
+        let mut parsed = parse_line(input).unwrap();
+        parsed.reverse();
+        let result = extrapolate(&parsed);
+
+        assert_eq!(result, Some(5));
+    }
+
+    #[test]
+    fn test_generated() {
+        for seed in 0..20 {
+            let puzzle = gen::histories(seed, 200, 21);
+            assert_eq!(Day9::solve_part1(&puzzle.input).unwrap() as u64, puzzle.part1.unwrap(), "{}", puzzle.input);
+            assert_eq!(Day9::solve_part2(&puzzle.input).unwrap() as u64, puzzle.part2.unwrap(), "{}", puzzle.input);
+        }
+    }
+}
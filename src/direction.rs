@@ -9,13 +9,13 @@ pub enum Direction {
 }
 
 impl Direction {
-    pub fn from_udlr(input: &str) -> Self {
+    pub fn from_udlr(input: &str) -> Option<Self> {
         match input {
-            "U" => Direction::North,
-            "D" => Direction::South,
-            "R" => Direction::East,
-            "L" => Direction::West,
-            x => panic!("Invalid direction: {}", x)
+            "U" => Some(Direction::North),
+            "D" => Some(Direction::South),
+            "R" => Some(Direction::East),
+            "L" => Some(Direction::West),
+            _ => None
         }
     }
 
@@ -37,6 +37,16 @@ impl Direction {
         }
     }
 
+    /// An arrow pointing this way, as used in the puzzle illustrations
+    pub fn arrow(&self) -> char {
+        match self {
+            Direction::North => '^',
+            Direction::East => '>',
+            Direction::South => 'v',
+            Direction::West => '<'
+        }
+    }
+
     pub fn opposite(&self) -> Direction {
         match self {
             Direction::North => Direction::South,
@@ -0,0 +1,169 @@
+use std::fmt::{Display, Formatter};
+use miette::{Diagnostic, SourceSpan};
+use thiserror::Error;
+
+/// Location of a parse error as a byte range, along with its 1-based line and column
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Span {
+    pub offset: usize,
+    pub len: usize,
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Span {
+    /// Locates `part`, which has to be a slice of `text`
+    pub fn of(text: &str, part: &str) -> Self {
+        let offset = (part.as_ptr() as usize).wrapping_sub(text.as_ptr() as usize);
+        assert!(offset <= text.len() && offset + part.len() <= text.len(), "{:?} is not a slice of the parsed text", part);
+
+        Self::at(text, offset, part.len())
+    }
+
+    /// An empty span at the end of `text`, for input that stopped too early
+    pub fn end(text: &str) -> Self {
+        Self::at(text, text.len(), 0)
+    }
+
+    fn at(text: &str, offset: usize, len: usize) -> Self {
+        let before = &text[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+
+        Self {
+            offset,
+            len,
+            line: before.matches('\n').count() + 1,
+            col: before[line_start..].chars().count() + 1,
+        }
+    }
+
+    /// Moves a span relative to `inner` to its position in `text`, where `inner` is a slice of `text`
+    pub fn within(self, text: &str, inner: &str) -> Self {
+        Self::at(text, Span::of(text, inner).offset + self.offset, self.len)
+    }
+}
+
+impl Display for Span {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}, column {}", self.line, self.col)
+    }
+}
+
+impl From<Span> for SourceSpan {
+    fn from(span: Span) -> Self {
+        (span.offset, span.len).into()
+    }
+}
+
+#[derive(Error, Diagnostic, Clone, Debug, PartialEq, Eq)]
+pub enum ParseError {
+    #[error("Expected {expected} at {span}")]
+    Expected {
+        expected: String,
+        #[label("expected {expected}")]
+        span: Span,
+    },
+    #[error("Invalid {what} {found:?} at {span}")]
+    Invalid {
+        what: &'static str,
+        found: String,
+        #[label("invalid {what}")]
+        span: Span,
+    },
+}
+
+impl ParseError {
+    /// `part` is where `expected` should have been, a slice of `text`
+    pub fn expected(expected: impl Into<String>, text: &str, part: &str) -> Self {
+        ParseError::Expected {
+            expected: expected.into(),
+            span: Span::of(text, part),
+        }
+    }
+
+    /// `text` ended before `expected` was found
+    pub fn expected_at_end(expected: impl Into<String>, text: &str) -> Self {
+        ParseError::Expected {
+            expected: expected.into(),
+            span: Span::end(text),
+        }
+    }
+
+    /// `part` is the offending slice of `text`
+    pub fn invalid(what: &'static str, text: &str, part: &str) -> Self {
+        ParseError::Invalid {
+            what,
+            found: part.to_string(),
+            span: Span::of(text, part),
+        }
+    }
+
+    pub fn span(&self) -> Span {
+        match self {
+            ParseError::Expected { span, .. } | ParseError::Invalid { span, .. } => *span,
+        }
+    }
+
+    /// Moves an error raised while parsing `inner` to its position in `text`, where `inner` is a slice of `text`
+    pub fn within(self, text: &str, inner: &str) -> Self {
+        match self {
+            ParseError::Expected { expected, span } => ParseError::Expected {
+                expected,
+                span: span.within(text, inner),
+            },
+            ParseError::Invalid { what, found, span } => ParseError::Invalid {
+                what,
+                found,
+                span: span.within(text, inner),
+            },
+        }
+    }
+
+    /// Attaches the full puzzle input, so the diagnostic can point at the offending line
+    pub fn with_source(self, input: &str) -> miette::Report {
+        miette::Report::new(self).with_source_code(input.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "first line\nsecond line\nthird";
+
+    #[test]
+    fn test_span_of() {
+        let span = Span::of(TEXT, &TEXT[18..22]);
+        assert_eq!(span, Span { offset: 18, len: 4, line: 2, col: 8 });
+        assert_eq!(span.to_string(), "line 2, column 8");
+
+        assert_eq!(Span::of(TEXT, &TEXT[..5]), Span { offset: 0, len: 5, line: 1, col: 1 });
+        assert_eq!(Span::end(TEXT), Span { offset: 28, len: 0, line: 3, col: 6 });
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_span_of_foreign_slice() {
+        Span::of(TEXT, "line");
+    }
+
+    #[test]
+    fn test_within() {
+        let third = TEXT.lines().nth(2).unwrap();
+        let err = ParseError::invalid("word", third, &third[1..3]).within(TEXT, third);
+
+        assert_eq!(err.span(), Span { offset: 24, len: 2, line: 3, col: 2 });
+        assert_eq!(err.to_string(), "Invalid word \"hi\" at line 3, column 2");
+    }
+
+    #[test]
+    fn test_diagnostic_label() {
+        let err = ParseError::expected("a digit", TEXT, &TEXT[11..17]);
+        let labels = err.labels().unwrap().collect::<Vec<_>>();
+
+        assert_eq!(labels.len(), 1);
+        assert_eq!(labels[0].offset(), 11);
+        assert_eq!(labels[0].len(), 6);
+        assert_eq!(labels[0].label(), Some("expected a digit"));
+    }
+}
@@ -0,0 +1,883 @@
+//! Seeded random puzzle inputs, for stress testing and benchmarking solvers on inputs larger than the real ones.
+//! The same seed always gives the same input.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+/// SplitMix64, small and fast, and good enough for puzzle inputs
+#[derive(Clone, Debug)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A number in `0..n`, `n` has to be positive
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// A number in `lo..=hi`
+    pub fn between(&mut self, lo: u64, hi: u64) -> u64 {
+        lo + self.below(hi - lo + 1)
+    }
+
+    /// True with probability `p`
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    /// Fisher-Yates
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for idx in (1..items.len()).rev() {
+            items.swap(idx, self.below(idx as u64 + 1) as usize);
+        }
+    }
+}
+
+/// A generated puzzle input, with the answers when the generator knows them without solving the puzzle
+#[derive(Clone, Debug)]
+pub struct Puzzle {
+    pub input: String,
+    pub part1: Option<u64>,
+    pub part2: Option<u64>,
+}
+
+impl Puzzle {
+    fn new(input: String) -> Self {
+        Self {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+}
+
+/// Clockwise around a cell, starting north. Consecutive entries are orthogonally adjacent.
+const RING: [(i64, i64); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A random polyomino of about `target` cells in a `width` x `height` box, without holes and without
+/// cells that only touch at a corner, so its outline is a single simple loop
+fn polyomino(rng: &mut Rng, width: usize, height: usize, target: usize) -> Vec<Vec<bool>> {
+    let mut cells = vec![vec![false; width]; height];
+    let mut members = vec![(rng.below(width as u64) as i64, rng.below(height as u64) as i64)];
+    cells[members[0].1 as usize][members[0].0 as usize] = true;
+
+    let is_in = |cells: &Vec<Vec<bool>>, x: i64, y: i64| {
+        x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height && cells[y as usize][x as usize]
+    };
+
+    for _ in 0..target * 20 {
+        if members.len() >= target {
+            break;
+        }
+
+        let &(x, y) = rng.choose(&members);
+        let (dx, dy) = RING[rng.below(4) as usize * 2];
+        let (x, y) = (x + dx, y + dy);
+        if x < 0 || y < 0 || x as usize >= width || y as usize >= height || is_in(&cells, x, y) {
+            continue;
+        }
+
+        // The cells around it have to form a single arc that includes an orthogonal neighbour,
+        // anything else would close a hole or pinch the outline
+        let ring = RING.map(|(dx, dy)| is_in(&cells, x + dx, y + dy));
+        let transitions = (0..8).filter(|&k| ring[k] != ring[(k + 1) % 8]).count();
+        let orthogonal = (0..8).step_by(2).any(|k| ring[k]);
+
+        if transitions == 2 && orthogonal {
+            cells[y as usize][x as usize] = true;
+            members.push((x, y));
+        }
+    }
+
+    cells
+}
+
+/// The corners of the outline of `cells` on the lattice of cell corners, clockwise
+fn outline(cells: &[Vec<bool>]) -> Vec<(i64, i64)> {
+    let is_in = |x: i64, y: i64| {
+        x >= 0 && y >= 0 && cells.get(y as usize).and_then(|row| row.get(x as usize)).copied().unwrap_or(false)
+    };
+
+    // Every boundary edge, directed so the polyomino is on its right
+    let mut next = HashMap::new();
+    for (y, row) in cells.iter().enumerate() {
+        for (x, _) in row.iter().enumerate().filter(|(_, &cell)| cell) {
+            let (x, y) = (x as i64, y as i64);
+            if !is_in(x, y - 1) { next.insert((x, y), (x + 1, y)); }
+            if !is_in(x + 1, y) { next.insert((x + 1, y), (x + 1, y + 1)); }
+            if !is_in(x, y + 1) { next.insert((x + 1, y + 1), (x, y + 1)); }
+            if !is_in(x - 1, y) { next.insert((x, y + 1), (x, y)); }
+        }
+    }
+
+    let start = *next.keys().min().unwrap();
+    let mut vertices = vec![start];
+    let mut cur = next[&start];
+    while cur != start {
+        vertices.push(cur);
+        cur = next[&cur];
+    }
+
+    // Only keep the corners
+    let n = vertices.len();
+    (0..n)
+        .filter(|&k| {
+            let (prev, cur, next) = (vertices[(k + n - 1) % n], vertices[k], vertices[(k + 1) % n]);
+            (cur.0 - prev.0, cur.1 - prev.1) != (next.0 - cur.0, next.1 - cur.1)
+        })
+        .map(|k| vertices[k])
+        .collect()
+}
+
+fn unit(from: (i64, i64), to: (i64, i64)) -> (i64, i64) {
+    ((to.0 - from.0).signum(), (to.1 - from.1).signum())
+}
+
+/// Letters that aren't in any spelled out digit, so filler never spells one by accident
+const FILLER: &[u8] = b"abcdjklmpqyz";
+
+const DIGIT_WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// Day 1: `lines` calibration lines of digits and spelled out digits between filler, with at least one real digit
+/// in each. Both answers are known.
+pub fn calibration(seed: u64, lines: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for _ in 0..lines {
+        // Digits, and whether they're spelled out
+        let mut tokens = (0..rng.between(1, 6)).map(|_| (rng.between(1, 9), rng.chance(0.5))).collect::<Vec<_>>();
+        let real = rng.below(tokens.len() as u64) as usize;
+        tokens[real].1 = false;
+
+        let digits = tokens.iter().filter(|(_, spelled)| !spelled).map(|&(digit, _)| digit).collect::<Vec<_>>();
+        part1 += digits[0] * 10 + digits[digits.len() - 1];
+        part2 += tokens[0].0 * 10 + tokens[tokens.len() - 1].0;
+
+        for &(digit, spelled) in &tokens {
+            input.extend((0..rng.below(4)).map(|_| *rng.choose(FILLER) as char));
+            match spelled {
+                true => input.push_str(DIGIT_WORDS[digit as usize - 1]),
+                false => input.push(char::from_digit(digit as u32, 10).unwrap()),
+            }
+        }
+        input.extend((0..rng.below(4)).map(|_| *rng.choose(FILLER) as char));
+        input.push('\n');
+    }
+
+    Puzzle {
+        input,
+        part1: Some(part1),
+        part2: Some(part2),
+    }
+}
+
+/// Day 2: `games` games of 1 to 6 draws, each of 1 to 20 cubes of some of the colors
+pub fn cube_games(seed: u64, games: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+
+    for id in 1..=games {
+        let draws = (0..rng.between(1, 6))
+            .map(|_| {
+                let mut colors = ["red", "green", "blue"];
+                rng.shuffle(&mut colors);
+                let cubes = colors[..rng.between(1, 3) as usize].iter()
+                    .map(|color| format!("{} {}", rng.between(1, 20), color))
+                    .collect::<Vec<_>>();
+                cubes.join(", ")
+            })
+            .collect::<Vec<_>>();
+        writeln!(input, "Game {}: {}", id, draws.join("; ")).unwrap();
+    }
+
+    Puzzle::new(input)
+}
+
+/// Day 3: an engine schematic of `width` x `height` tiles with numbers of 1 to 3 digits and symbols scattered over it
+pub fn schematic(seed: u64, width: usize, height: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    let mut input = String::with_capacity((width + 1) * height);
+
+    for _ in 0..height {
+        let mut row = String::new();
+        while row.len() < width {
+            let digits = rng.between(1, 3) as usize;
+            if rng.chance(0.15) && row.len() + digits <= width {
+                write!(row, "{}", rng.between(10u64.pow(digits as u32 - 1), 10u64.pow(digits as u32) - 1)).unwrap();
+                // Numbers next to each other would run together
+                if row.len() < width {
+                    row.push('.');
+                }
+            } else if rng.chance(0.1) {
+                row.push(*rng.choose(&['*', '#', '+', '$', '/', '=', '%', '@', '&', '-']));
+            } else {
+                row.push('.');
+            }
+        }
+        input.push_str(&row);
+        input.push('\n');
+    }
+
+    Puzzle::new(input)
+}
+
+/// Day 4: `cards` scratchcards with `winning` winning numbers and `have` numbers each, all from 1 to 99.
+/// No card wins copies of cards past the last one. Both answers are known.
+pub fn scratchcards(seed: u64, cards: usize, winning: usize, have: usize) -> Puzzle {
+    assert!(winning + have <= 99, "There are only 99 numbers to draw from");
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut matches = Vec::new();
+
+    for id in 1..=cards {
+        let k = rng.between(0, winning.min(have).min(cards - id) as u64) as usize;
+        matches.push(k);
+
+        // The first `k` winning numbers are the ones on the card too
+        let mut pool = (1..=99).collect::<Vec<u64>>();
+        rng.shuffle(&mut pool);
+        let mut numbers = pool[..k].iter().chain(&pool[winning..winning + have - k]).copied().collect::<Vec<_>>();
+        rng.shuffle(&mut numbers);
+
+        let format = |numbers: &[u64]| numbers.iter().map(|n| format!("{:>2}", n)).collect::<Vec<_>>().join(" ");
+        writeln!(input, "Card {:>3}: {} | {}", id, format(&pool[..winning]), format(&numbers)).unwrap();
+    }
+
+    // Every copy of a card wins one more copy of each of the next cards it matches
+    let mut copies = vec![1; cards];
+    for (idx, &k) in matches.iter().enumerate() {
+        for next in idx + 1..=idx + k {
+            copies[next] += copies[idx];
+        }
+    }
+
+    Puzzle {
+        input,
+        part1: Some(matches.iter().filter(|&&k| k > 0).map(|&k| 1 << (k - 1)).sum()),
+        part2: Some(copies.iter().sum()),
+    }
+}
+
+/// Day 5: an almanac of `seeds` seed ranges and `maps` mappings chained from seed to location, each with up to
+/// `ranges` disjoint ranges. Every number is below `max`.
+pub fn almanac(seed: u64, seeds: usize, maps: usize, ranges: usize, max: u64) -> Puzzle {
+    assert!(maps >= 1 && max >= 2, "An almanac needs a mapping and room for ranges");
+    let mut rng = Rng::new(seed);
+
+    let seeds = (0..seeds)
+        .map(|_| {
+            let start = rng.below(max);
+            format!("{} {}", start, rng.between(1, max - start))
+        })
+        .collect::<Vec<_>>();
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    let categories = std::iter::once("seed".to_string())
+        .chain((1..maps).map(|idx| format!("category{}", idx)))
+        .chain(std::iter::once("location".to_string()))
+        .collect::<Vec<_>>();
+
+    for (source, destination) in categories.iter().zip(&categories[1..]) {
+        // Every other gap between the cuts is a range, so they never overlap
+        let mut cuts = (0..ranges * 2).map(|_| rng.below(max)).collect::<Vec<_>>();
+        cuts.sort_unstable();
+        cuts.dedup();
+
+        writeln!(input, "\n{}-to-{} map:", source, destination).unwrap();
+        for pair in cuts.chunks_exact(2) {
+            let len = pair[1] - pair[0];
+            writeln!(input, "{} {} {}", rng.below(max - len), pair[0], len).unwrap();
+        }
+    }
+
+    Puzzle::new(input)
+}
+
+/// Day 6: `races` races of 2 to `max_time` ms, with records that can mostly be beaten,
+/// but now and then tie with or go past the best distance so no hold wins.
+/// Part 1 is counted by trying every hold.
+pub fn races(seed: u64, races: usize, max_time: u64) -> Puzzle {
+    // Part 2 joins the digits of all the records into one number
+    let max_record = max_time * max_time / 4;
+    assert!(races * (max_record.to_string().len()) <= 18, "The joined race records don't fit in an i64");
+    let mut rng = Rng::new(seed);
+
+    let races = (0..races)
+        .map(|_| {
+            let time = rng.between(2, max_time);
+            let best = (time / 2) * (time - time / 2);
+            let record = match rng.below(10) {
+                0 => best,
+                1 => rng.between(best, max_record),
+                _ => rng.below(best),
+            };
+            (time, record)
+        })
+        .collect::<Vec<_>>();
+
+    let ways = races.iter()
+        .map(|&(time, record)| (0..=time).filter(|hold| hold * (time - hold) > record).count() as u64)
+        .product();
+
+    let row = |numbers: Vec<u64>| numbers.iter().map(|n| format!("{:>5}", n)).collect::<String>();
+    Puzzle {
+        part1: Some(ways),
+        ..Puzzle::new(format!(
+            "Time:    {}\nDistance:{}\n",
+            row(races.iter().map(|race| race.0).collect()),
+            row(races.iter().map(|race| race.1).collect()),
+        ))
+    }
+}
+
+/// Day 7: `hands` different hands of camel cards, with bids of 1 to 1000
+pub fn camel_hands(seed: u64, hands: usize) -> Puzzle {
+    assert!(hands <= 13usize.pow(5), "There are only 13^5 different hands");
+    let mut rng = Rng::new(seed);
+    let cards = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+    // Pairs and worse are what random hands mostly are, so some cards are copied to make better ones
+    let mut seen = HashSet::new();
+    let mut input = String::new();
+    while seen.len() < hands {
+        let mut hand = [(); 5].map(|_| *rng.choose(&cards));
+        for _ in 0..rng.below(4) {
+            hand[rng.below(5) as usize] = hand[rng.below(5) as usize];
+        }
+
+        if seen.insert(hand) {
+            writeln!(input, "{} {}", hand.iter().collect::<String>(), rng.between(1, 1000)).unwrap();
+        }
+    }
+
+    Puzzle::new(input)
+}
+
+/// Day 8: `ghosts` ghosts each walking their own chain of nodes, from a node ending in A to one ending in Z and round
+/// again, following `steps` instructions. Every wrong turn leads to a dead end. The first ghost walks from AAA to ZZZ.
+/// Both answers are known.
+pub fn network(seed: u64, steps: usize, ghosts: usize) -> Puzzle {
+    assert!(steps >= 1 && ghosts >= 1, "A network needs steps and a ghost walking them");
+    let mut rng = Rng::new(seed);
+    let steps = (0..steps).map(|_| *rng.choose(&['L', 'R'])).collect::<String>();
+
+    let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string(), "XXX".to_string()]);
+    let mut name = |rng: &mut Rng, last: char| loop {
+        let name = (0..2).map(|_| (b'A' + rng.below(26) as u8) as char).chain([last]).collect::<String>();
+        if used.insert(name.clone()) {
+            break name;
+        }
+    };
+
+    let mut nodes = vec![("XXX".to_string(), "XXX".to_string(), "XXX".to_string())];
+    let mut lengths = Vec::new();
+    for ghost in 0..ghosts {
+        // Each ghost gets to its end after a whole number of passes through the steps, and the end leads on like the start
+        let length = steps.len() * rng.between(1, 4) as usize;
+        lengths.push(length as u64);
+
+        let mut chain = match ghost {
+            0 => vec!["AAA".to_string()],
+            _ => vec![name(&mut rng, 'A')],
+        };
+        for _ in 1..length {
+            let last = (b'B' + rng.below(23) as u8) as char;
+            chain.push(name(&mut rng, last));
+        }
+        chain.push(match ghost {
+            0 => "ZZZ".to_string(),
+            _ => name(&mut rng, 'Z'),
+        });
+
+        for (idx, from) in chain.iter().enumerate() {
+            let (next, step) = match idx {
+                idx if idx == length => (&chain[1], steps.as_bytes()[0]),
+                idx => (&chain[idx + 1], steps.as_bytes()[idx % steps.len()]),
+            };
+            let (left, right) = match step {
+                b'L' => (next.clone(), "XXX".to_string()),
+                _ => ("XXX".to_string(), next.clone()),
+            };
+            nodes.push((from.clone(), left, right));
+        }
+    }
+
+    rng.shuffle(&mut nodes);
+    let mut input = format!("{}\n\n", steps);
+    for (from, left, right) in nodes {
+        writeln!(input, "{} = ({}, {})", from, left, right).unwrap();
+    }
+
+    Puzzle {
+        input,
+        part1: Some(lengths[0]),
+        part2: Some(lengths.iter().fold(1, |acc, &length| acc / gcd(acc, length) * length)),
+    }
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        b => gcd(b, a % b),
+    }
+}
+
+/// Day 9: `histories` histories of `len` values (2 to 21) of polynomials of degree up to 4, shifted so every value
+/// and both answers are positive. Both answers are known.
+pub fn histories(seed: u64, histories: usize, len: usize) -> Puzzle {
+    assert!((2..=21).contains(&len), "Histories need 2 to 21 values");
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let (mut part1, mut part2) = (0, 0);
+
+    for _ in 0..histories {
+        // The differences have to get to all zeroes before they run out, which takes one more than the degree
+        let degree = rng.between(0, 4.min(len as u64 - 2));
+        let coefficients = (0..=degree).map(|_| rng.between(0, 6) as i64 - 3).collect::<Vec<_>>();
+        let values = (-1..=len as i64)
+            .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c))
+            .collect::<Vec<_>>();
+        let shift = rng.between(0, 100) as i64 - values.iter().min().unwrap().min(&0);
+
+        let history = values[1..=len].iter().map(|value| (value + shift).to_string()).collect::<Vec<_>>();
+        writeln!(input, "{}", history.join(" ")).unwrap();
+        part1 += (values[len + 1] + shift) as u64;
+        part2 += (values[0] + shift) as u64;
+    }
+
+    Puzzle {
+        input,
+        part1: Some(part1),
+        part2: Some(part2),
+    }
+}
+
+/// Day 10: a pipe maze of `width` x `height` tiles (at least 3 each) with a single loop through the start,
+/// surrounded by junk pipes. Both answers are known.
+pub fn pipe_maze(seed: u64, width: usize, height: usize) -> Puzzle {
+    assert!(width >= 3 && height >= 3, "A pipe maze needs at least 3x3 tiles");
+    let mut rng = Rng::new(seed);
+
+    // Cell corners of the polyomino become the even tiles, so every loop tile is two tiles from the
+    // next corner or the middle of an edge
+    let (cells_x, cells_y) = ((width - 1) / 2, (height - 1) / 2);
+    let n_cells = rng.between(1, (cells_x * cells_y) as u64) as usize;
+    let cells = polyomino(&mut rng, cells_x, cells_y, n_cells);
+    let n_cells = cells.iter().flatten().filter(|&&cell| cell).count();
+    let corners = outline(&cells);
+
+    let mut path = Vec::new();
+    for (k, &from) in corners.iter().enumerate() {
+        let to = corners[(k + 1) % corners.len()];
+        let (dx, dy) = unit(from, to);
+        let mut cur = (from.0 * 2, from.1 * 2);
+        while cur != (to.0 * 2, to.1 * 2) {
+            path.push(cur);
+            cur = (cur.0 + dx, cur.1 + dy);
+        }
+    }
+
+    let junk = ['.', '.', '.', '|', '-', 'L', 'J', '7', 'F'];
+    let mut tiles = (0..height)
+        .map(|_| (0..width).map(|_| *rng.choose(&junk)).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    let n = path.len();
+    for k in 0..n {
+        let (prev, cur, next) = (path[(k + n - 1) % n], path[k], path[(k + 1) % n]);
+        let mut ends = [unit(cur, prev), unit(cur, next)];
+        ends.sort();
+
+        tiles[cur.1 as usize][cur.0 as usize] = match ends {
+            [(0, -1), (0, 1)] => '|',
+            [(-1, 0), (1, 0)] => '-',
+            [(0, -1), (1, 0)] => 'L',
+            [(-1, 0), (0, -1)] => 'J',
+            [(-1, 0), (0, 1)] => '7',
+            [(0, 1), (1, 0)] => 'F',
+            ends => unreachable!("Loop doubles back on itself: {:?}", ends),
+        };
+    }
+
+    let start = path[rng.below(n as u64) as usize];
+    tiles[start.1 as usize][start.0 as usize] = 'S';
+
+    // Junk next to the start mustn't look like it connects to it
+    for (dx, dy) in RING.iter().step_by(2) {
+        let (x, y) = (start.0 + dx, start.1 + dy);
+        if x >= 0 && y >= 0 && (x as usize) < width && (y as usize) < height && !path.contains(&(x, y)) {
+            tiles[y as usize][x as usize] = '.';
+        }
+    }
+
+    let mut input = String::new();
+    for row in tiles {
+        input.extend(row);
+        input.push('\n');
+    }
+
+    // Each cell of the polyomino covers 2x2 tiles, Pick's theorem gives the tiles inside the loop
+    Puzzle {
+        input,
+        part1: Some(n as u64 / 2),
+        part2: Some((4 * n_cells + 1 - n / 2) as u64),
+    }
+}
+
+/// Day 11: `width` x `height` tiles where each is a galaxy with probability `density`
+pub fn galaxies(seed: u64, width: usize, height: usize, density: f64) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    Puzzle::new(grid(width, height, || if rng.chance(density) { '#' } else { '.' }))
+}
+
+/// Day 12: `rows` spring records up to `max_len` long with at most 12 unknown springs each,
+/// so the arrangements for part 1 can be counted by trying every assignment
+pub fn spring_records(seed: u64, rows: usize, max_len: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    let mut input = String::new();
+    let mut arrangements = 0;
+
+    for _ in 0..rows {
+        let len = rng.between(1, max_len.max(1) as u64) as usize;
+        let mut springs = (0..len)
+            .map(|_| if rng.chance(0.5) { '#' } else { '.' })
+            .collect::<Vec<_>>();
+        let broken = rng.below(len as u64) as usize;
+        springs[broken] = '#';
+
+        let groups = damaged_groups(&springs);
+
+        let mut unknown = Vec::new();
+        for (idx, spring) in springs.iter_mut().enumerate() {
+            if unknown.len() < 12 && rng.chance(0.4) {
+                *spring = '?';
+                unknown.push(idx);
+            }
+        }
+
+        arrangements += (0..1u32 << unknown.len())
+            .filter(|assignment| {
+                let mut candidate = springs.clone();
+                for (bit, &idx) in unknown.iter().enumerate() {
+                    candidate[idx] = if assignment & (1 << bit) != 0 { '#' } else { '.' };
+                }
+                damaged_groups(&candidate) == groups
+            })
+            .count() as u64;
+
+        let groups = groups.iter().map(|group| group.to_string()).collect::<Vec<_>>();
+        writeln!(input, "{} {}", springs.iter().collect::<String>(), groups.join(",")).unwrap();
+    }
+
+    Puzzle {
+        part1: Some(arrangements),
+        ..Puzzle::new(input)
+    }
+}
+
+fn damaged_groups(springs: &[char]) -> Vec<usize> {
+    springs.split(|&spring| spring != '#')
+        .filter(|group| !group.is_empty())
+        .map(|group| group.len())
+        .collect()
+}
+
+/// Day 13: `patterns` patterns of 3 to `max_size` tiles each way, each with a perfect reflection and another one
+/// with a single smudge, both horizontal or both vertical. Other reflections can turn up by chance.
+pub fn mirror_patterns(seed: u64, patterns: usize, max_size: usize) -> Puzzle {
+    assert!(max_size >= 3, "Patterns need room for two reflections");
+    let mut rng = Rng::new(seed);
+    let mut input = Vec::new();
+
+    while input.len() < patterns {
+        let (width, height) = (rng.between(3, max_size as u64) as usize, rng.between(3, max_size as u64) as usize);
+        let (perfect, smudged) = (rng.between(1, height as u64 - 1) as usize, rng.between(1, height as u64 - 1) as usize);
+
+        // The rows each reflection maps onto each other
+        let reflected = |split: usize| (0..split.min(height - split)).map(move |i| (split - 1 - i, split + i));
+        let perfect_rows = reflected(perfect).flat_map(|(a, b)| [a, b]).collect::<HashSet<_>>();
+
+        // The smudge has to be outside the perfect reflection, or it would spoil it
+        let Some((smudge, _)) = reflected(smudged).find(|(a, _)| !perfect_rows.contains(a)) else {
+            continue;
+        };
+        if perfect == smudged {
+            continue;
+        }
+
+        // Rows that have to be the same share a class, every class gets its own random row
+        let mut class = (0..height).collect::<Vec<_>>();
+        for (a, b) in reflected(perfect).chain(reflected(smudged)) {
+            let (from, to) = (class[a].max(class[b]), class[a].min(class[b]));
+            class.iter_mut().filter(|c| **c == from).for_each(|c| *c = to);
+        }
+        let rows = (0..height)
+            .map(|_| (0..width).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        let mut tiles = class.iter().map(|&c| rows[c].clone()).collect::<Vec<_>>();
+
+        let x = rng.below(width as u64) as usize;
+        tiles[smudge][x] = if tiles[smudge][x] == '#' { '.' } else { '#' };
+
+        if rng.chance(0.5) {
+            tiles = (0..width).map(|x| tiles.iter().map(|row| row[x]).collect()).collect();
+        }
+        input.push(tiles.iter().map(|row| row.iter().collect::<String>() + "\n").collect::<String>());
+    }
+
+    Puzzle::new(input.join("\n"))
+}
+
+/// Day 14: a `size` x `size` platform of round and cube shaped rocks
+pub fn platform(seed: u64, size: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    Puzzle::new(grid(size, size, || match rng.below(10) {
+        0..=1 => 'O',
+        2 => '#',
+        _ => '.',
+    }))
+}
+
+/// Day 15: `steps` initialization steps, each adding or removing one of `labels` labels of 1 to 6 letters
+pub fn init_sequence(seed: u64, steps: usize, labels: usize) -> Puzzle {
+    assert!(labels >= 1, "Steps need a label");
+    let mut rng = Rng::new(seed);
+    let labels = (0..labels)
+        .map(|_| (0..rng.between(1, 6)).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>())
+        .collect::<Vec<_>>();
+
+    let steps = (0..steps)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            match rng.chance(0.3) {
+                true => format!("{}-", label),
+                false => format!("{}={}", label, rng.between(1, 9)),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Puzzle::new(steps.join(",") + "\n")
+}
+
+/// Day 16: `width` x `height` tiles where each is a mirror or splitter with probability `density`
+pub fn mirrors(seed: u64, width: usize, height: usize, density: f64) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    Puzzle::new(grid(width, height, || if rng.chance(density) { *rng.choose(&['/', '\\', '-', '|']) } else { '.' }))
+}
+
+/// Day 17: `width` x `height` blocks with a heat loss of 1 to 9 each
+pub fn heat_map(seed: u64, width: usize, height: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    Puzzle::new(grid(width, height, || char::from_digit(rng.between(1, 9) as u32, 10).unwrap()))
+}
+
+fn grid(width: usize, height: usize, mut tile: impl FnMut() -> char) -> String {
+    let mut input = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        input.extend((0..width).map(|_| tile()));
+        input.push('\n');
+    }
+    input
+}
+
+/// Day 18: a dig plan tracing a simple polygon on a grid of `size` x `size` stretched cells. The colors encode
+/// a second polygon with the same corners stretched much further, both answers are known.
+pub fn dig_plan(seed: u64, size: usize) -> Puzzle {
+    // Every cell is stretched at least 2 and the colors hold 5 hex digits
+    assert!((1..=0xFFFFF / 2).contains(&size), "Dig plans have 1 to {} cells each way", 0xFFFFF / 2);
+    let mut rng = Rng::new(seed);
+    let target = rng.between(1, (size * size) as u64) as usize;
+    let cells = polyomino(&mut rng, size, size, target);
+    let corners = outline(&cells);
+
+    // Every row and column of cells is stretched to its own size, the colors can hold 5 hex digits.
+    // At least 2, so the trench never runs alongside itself and seals off ground outside the lagoon.
+    let mut stretch = |max: u64| {
+        let widths = (0..size).map(|_| rng.between(2, max) as i64).collect::<Vec<_>>();
+        let heights = (0..size).map(|_| rng.between(2, max) as i64).collect::<Vec<_>>();
+        (widths, heights)
+    };
+    let small = stretch(10);
+    let large = stretch(0xFFFFF / size as u64);
+
+    let offsets = |sizes: &[i64]| std::iter::once(0).chain(sizes.iter().scan(0, |sum, size| {
+        *sum += size;
+        Some(*sum)
+    })).collect::<Vec<_>>();
+    let (small_x, small_y) = (offsets(&small.0), offsets(&small.1));
+    let (large_x, large_y) = (offsets(&large.0), offsets(&large.1));
+
+    let mut input = String::new();
+    let (mut small_trench, mut large_trench) = (0, 0);
+    for (k, &from) in corners.iter().enumerate() {
+        let to = corners[(k + 1) % corners.len()];
+        let (direction, digit) = match unit(from, to) {
+            (1, 0) => ('R', 0),
+            (0, 1) => ('D', 1),
+            (-1, 0) => ('L', 2),
+            _ => ('U', 3),
+        };
+
+        let length = |xs: &[i64], ys: &[i64]| {
+            (xs[to.0 as usize] - xs[from.0 as usize]).abs() + (ys[to.1 as usize] - ys[from.1 as usize]).abs()
+        };
+        let (small_length, large_length) = (length(&small_x, &small_y), length(&large_x, &large_y));
+        small_trench += small_length;
+        large_trench += large_length;
+
+        writeln!(input, "{} {} (#{:05x}{})", direction, small_length, large_length, digit).unwrap();
+    }
+
+    // The lagoon holds the area of the stretched cells, plus the outer half of the trench and one for the corners
+    let lagoon = |(widths, heights): &(Vec<i64>, Vec<i64>), trench: i64| {
+        let area = cells.iter().enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, &cell)| cell).map(move |(x, _)| (x, y)))
+            .map(|(x, y)| widths[x] * heights[y])
+            .sum::<i64>();
+        (area + trench / 2 + 1) as u64
+    };
+
+    Puzzle {
+        input,
+        part1: Some(lagoon(&small, small_trench)),
+        part2: Some(lagoon(&large, large_trench)),
+    }
+}
+
+/// Day 19: a tree of about `n_workflows` workflows starting at `in`, followed by `n_parts` random parts
+pub fn workflows(seed: u64, n_workflows: usize, n_parts: usize) -> Puzzle {
+    let mut rng = Rng::new(seed);
+    let mut names = vec!["in".to_string()];
+    let mut input = String::new();
+
+    // A new workflow while there is room for more, otherwise a verdict
+    let target = |rng: &mut Rng, names: &mut Vec<String>| {
+        if names.len() < n_workflows && rng.chance(0.6) {
+            let name = loop {
+                let len = rng.between(2, 4);
+                let name = (0..len).map(|_| (b'a' + rng.below(26) as u8) as char).collect::<String>();
+                if !names.contains(&name) {
+                    break name;
+                }
+            };
+            names.push(name.clone());
+            name
+        } else {
+            rng.choose(&["A", "R"]).to_string()
+        }
+    };
+
+    let mut idx = 0;
+    while idx < names.len() {
+        let mut rules = (0..rng.between(1, 3))
+            .map(|_| {
+                let category = rng.choose(&['x', 'm', 'a', 's']);
+                let operator = rng.choose(&['<', '>']);
+                format!("{}{}{}:{}", category, operator, rng.between(1, 4000), target(&mut rng, &mut names))
+            })
+            .collect::<Vec<_>>();
+        rules.push(target(&mut rng, &mut names));
+
+        writeln!(input, "{}{{{}}}", names[idx], rules.join(",")).unwrap();
+        idx += 1;
+    }
+
+    input.push('\n');
+    for _ in 0..n_parts {
+        let [x, m, a, s] = [(); 4].map(|_| rng.between(1, 4000));
+        writeln!(input, "{{x={},m={},a={},s={}}}", x, m, a, s).unwrap();
+    }
+
+    Puzzle::new(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seeded() {
+        assert_eq!(heat_map(7, 20, 10).input, heat_map(7, 20, 10).input);
+        assert_ne!(heat_map(7, 20, 10).input, heat_map(8, 20, 10).input);
+
+        let input = mirrors(1, 12, 5, 0.3).input;
+        assert_eq!(input.lines().count(), 5);
+        assert!(input.lines().all(|line| line.len() == 12));
+    }
+
+    #[test]
+    fn test_polyomino_outline() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let cells = polyomino(&mut rng, 6, 5, 18);
+            let corners = outline(&cells);
+
+            // A simple loop never visits a corner twice, and alternates between horizontal and vertical
+            let mut unique = corners.clone();
+            unique.sort();
+            unique.dedup();
+            assert_eq!(unique.len(), corners.len());
+            assert_eq!(corners.len() % 2, 0);
+        }
+    }
+
+    #[test]
+    fn test_pipe_maze() {
+        let puzzle = pipe_maze(3, 11, 9);
+        assert_eq!(puzzle.input.lines().count(), 9);
+        assert!(puzzle.input.lines().all(|line| line.chars().count() == 11));
+        assert_eq!(puzzle.input.matches('S').count(), 1);
+
+        // A single cell is a loop of 8 tiles round one
+        let puzzle = pipe_maze(0, 3, 3);
+        assert_eq!((puzzle.part1, puzzle.part2), (Some(4), Some(1)));
+    }
+
+    #[test]
+    fn test_spring_records() {
+        let puzzle = spring_records(5, 1, 1);
+        assert_eq!(puzzle.input.trim_end().split_once(' ').map(|(_, groups)| groups), Some("1"));
+        assert_eq!(puzzle.part1, Some(1));
+    }
+
+    #[test]
+    fn test_dig_plan() {
+        let puzzle = dig_plan(11, 4);
+        assert!(puzzle.input.lines().count() >= 4);
+        assert!(puzzle.input.lines().all(|line| line.ends_with(')')));
+    }
+
+    #[test]
+    #[should_panic(expected = "Dig plans have 1 to")]
+    fn test_dig_plan_too_large() {
+        dig_plan(0, 0xFFFFF / 2 + 1);
+    }
+
+    #[test]
+    fn test_shuffle() {
+        let mut rng = Rng::new(3);
+        let mut items = (0..20).collect::<Vec<_>>();
+        rng.shuffle(&mut items);
+
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+}
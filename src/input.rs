@@ -0,0 +1,99 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use miette::{miette, IntoDiagnostic, WrapErr};
+
+/// Overrides the directory puzzle inputs are looked up in
+pub const INPUTS_DIR_ENV: &str = "AOC_INPUTS";
+
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    /// Looks up `dayNN.txt` in the directory
+    Dir(PathBuf),
+}
+
+impl InputSource {
+    /// Picks the source from a CLI argument, where `-` means stdin and no argument means the inputs directory
+    pub fn from_arg(path: Option<PathBuf>, inputs_dir: Option<PathBuf>) -> Self {
+        match path {
+            Some(path) if path == Path::new("-") => InputSource::Stdin,
+            Some(path) => InputSource::File(path),
+            None => InputSource::Dir(inputs_dir.unwrap_or_else(default_dir)),
+        }
+    }
+
+    pub fn load(&self, day: u32) -> miette::Result<String> {
+        match self {
+            InputSource::File(path) => read_file(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                std::io::stdin().read_to_string(&mut input)
+                    .into_diagnostic()
+                    .wrap_err("Couldn't read input from stdin")?;
+                Ok(input)
+            }
+            InputSource::Dir(dir) => {
+                let path = dir.join(file_name(day));
+                if !path.is_file() {
+                    return Err(miette!(
+                        help = format!("save the puzzle input as {}, set {} or pass --input", path.display(), INPUTS_DIR_ENV),
+                        "No input found for day {}",
+                        day
+                    ));
+                }
+                read_file(&path)
+            }
+        }
+    }
+}
+
+fn read_file(path: &Path) -> miette::Result<String> {
+    std::fs::read_to_string(path)
+        .into_diagnostic()
+        .wrap_err_with(|| format!("Couldn't read input file {}", path.display()))
+}
+
+pub fn file_name(day: u32) -> String {
+    format!("day{:02}.txt", day)
+}
+
+/// The `inputs` directory of the workspace, unless overridden through [`INPUTS_DIR_ENV`]
+pub fn default_dir() -> PathBuf {
+    std::env::var_os(INPUTS_DIR_ENV)
+        .map(PathBuf::from)
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("inputs"))
+}
+
+/// Loads a day's input from the default inputs directory
+pub fn load(day: u32) -> miette::Result<String> {
+    InputSource::Dir(default_dir()).load(day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_name() {
+        assert_eq!(file_name(1), "day01.txt");
+        assert_eq!(file_name(19), "day19.txt");
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let source = InputSource::Dir(PathBuf::from("does/not/exist"));
+        let err = source.load(3).unwrap_err();
+        assert_eq!(err.to_string(), "No input found for day 3");
+        assert!(err.help().unwrap().to_string().contains("day03.txt"));
+    }
+
+    #[test]
+    fn test_from_arg() {
+        assert!(matches!(InputSource::from_arg(Some(PathBuf::from("-")), None), InputSource::Stdin));
+        assert!(matches!(InputSource::from_arg(Some(PathBuf::from("in.txt")), None), InputSource::File(_)));
+        assert!(matches!(
+            InputSource::from_arg(None, Some(PathBuf::from("elsewhere"))),
+            InputSource::Dir(dir) if dir == Path::new("elsewhere")
+        ));
+    }
+}
@@ -1,3 +1,30 @@
-pub mod world;
+use std::fmt::Display;
+
 pub mod direction;
 pub mod map2d;
+pub mod input;
+pub mod error;
+pub mod parse;
+pub mod search;
+pub mod gen;
+
+/// A day's puzzle, split into a parsing stage shared by both parts and the two solving stages.
+pub trait Solution {
+    type Parsed<'a>;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    /// Parse errors should carry the input as source code, see [`error::ParseError::with_source`]
+    fn parse(input: &str) -> miette::Result<Self::Parsed<'_>>;
+    /// The parts fail on input that parses but can't be solved, like a maze without a way through
+    fn part1(parsed: &Self::Parsed<'_>) -> miette::Result<Self::Answer1>;
+    fn part2(parsed: &Self::Parsed<'_>) -> miette::Result<Self::Answer2>;
+
+    fn solve_part1(input: &str) -> miette::Result<Self::Answer1> {
+        Self::part1(&Self::parse(input)?)
+    }
+
+    fn solve_part2(input: &str) -> miette::Result<Self::Answer2> {
+        Self::part2(&Self::parse(input)?)
+    }
+}
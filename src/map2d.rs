@@ -1,27 +1,56 @@
 use std::fmt::{Debug, Display, Formatter};
+use std::ops::{Index, IndexMut};
 use std::slice::{Chunks, Iter};
 use glam::{IVec2};
+use crate::direction::Direction;
+use crate::error::ParseError;
+use crate::parse;
 
-#[derive(Clone, Eq, PartialEq)]
+/// Clockwise, starting north
+const NEIGHBOURS_8: [IVec2; 8] = [
+    IVec2::new(0, -1),
+    IVec2::new(1, -1),
+    IVec2::new(1, 0),
+    IVec2::new(1, 1),
+    IVec2::new(0, 1),
+    IVec2::new(-1, 1),
+    IVec2::new(-1, 0),
+    IVec2::new(-1, -1),
+];
+
+/// How positions that step off the grid are treated
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum Edges {
+    /// They're skipped
+    Bounded,
+    /// They wrap around to the opposite side, as on a torus
+    Wrapping,
+}
+
+/// A rectangular grid stored row by row
+#[derive(Clone, Eq, PartialEq, Hash)]
 pub struct Map2D<T> {
     buf: Vec<T>,
     pub width: usize,
     pub height: usize,
 }
 
-impl Map2D<char> {
-    pub fn new(input: &str) -> Self {
-        let mut buf = Vec::new();
-        let mut width = 0;
-        let mut height = 0;
-
-        for (y, line) in input.lines().enumerate() {
-            height = y + 1;
-            for (x, c) in line.chars().enumerate() {
-                width = x + 1;
-                buf.push(c);
-            }
-        }
+impl<T> Map2D<T> {
+    /// Parses one tile per character with `f`, rejecting characters it returns `None` for
+    pub fn parse(input: &str, f: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let (buf, width, height) = parse::grid(input, "tile", f)?;
+
+        Ok(Self {
+            buf,
+            width,
+            height,
+        })
+    }
+
+    /// Takes the tiles row by row, `buf` has to hold a multiple of `width` tiles
+    pub fn from_vec(buf: Vec<T>, width: usize) -> Self {
+        let height = buf.len().checked_div(width).unwrap_or(0);
+        assert_eq!(width * height, buf.len(), "{} tiles don't fit rows of width {}", buf.len(), width);
 
         Self {
             buf,
@@ -29,31 +58,130 @@ impl Map2D<char> {
             height,
         }
     }
-}
 
-impl Map2D<u32> {
-    pub fn new(input: &str) -> Self {
-        let mut buf = Vec::new();
-        let mut width = 0;
-        let mut height = 0;
-
-        for (y, line) in input.lines().enumerate() {
-            height = y + 1;
-            for (x, c) in line.chars().enumerate() {
-                width = x + 1;
-                buf.push(c.to_digit(10).unwrap());
-            }
+    pub fn is_in_bounds(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height
+    }
+
+    /// Like [`Map2D::is_in_bounds`], negative coordinates are out of bounds
+    pub fn contains(&self, pos: IVec2) -> bool {
+        self.to_xy(pos).is_some()
+    }
+
+    fn to_xy(&self, pos: IVec2) -> Option<(usize, usize)> {
+        let x = usize::try_from(pos.x).ok()?;
+        let y = usize::try_from(pos.y).ok()?;
+        self.is_in_bounds(x, y).then_some((x, y))
+    }
+
+    pub fn get_ref(&self, x: usize, y: usize) -> Option<&T> {
+        if !self.is_in_bounds(x, y) {
+            return None;
         }
+        self.buf.get(x + y * self.width)
+    }
 
-        Self {
-            buf,
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if !self.is_in_bounds(x, y) {
+            return None;
+        }
+        self.buf.get_mut(x + y * self.width)
+    }
+
+    pub fn get_v_ref(&self, pos: IVec2) -> Option<&T> {
+        let (x, y) = self.to_xy(pos)?;
+        self.get_ref(x, y)
+    }
+
+    pub fn set(&mut self, x: usize, y: usize, c: T) {
+        if !self.is_in_bounds(x, y) { panic!("out of bounds") }
+        self.buf[x + y * self.width] = c;
+    }
+
+    pub fn set_v(&mut self, pos: IVec2, c: T) {
+        let Some((x, y)) = self.to_xy(pos) else { panic!("out of bounds") };
+        self.set(x, y, c);
+    }
+
+    pub fn iter_rows(&self) -> Chunks<'_, T> {
+        self.buf.chunks(self.width.max(1))
+    }
+
+    pub fn iter_row(&self, row: usize) -> Iter<'_, T> {
+        self.buf[row * self.width..row * self.width + self.width].iter()
+    }
+
+    /// All tiles row by row, along with their position
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &T)> + '_ {
+        self.buf.iter()
+            .enumerate()
+            .map(|(idx, tile)| ((idx % self.width, idx / self.width), tile))
+    }
+
+    /// Maps `pos` onto the grid as if it repeated in every direction
+    pub fn wrap(&self, pos: IVec2) -> IVec2 {
+        if self.buf.is_empty() {
+            return pos;
+        }
+        IVec2::new(pos.x.rem_euclid(self.width as i32), pos.y.rem_euclid(self.height as i32))
+    }
+
+    fn step(&self, pos: IVec2, delta: IVec2, edges: Edges) -> Option<(IVec2, &T)> {
+        let next = match edges {
+            Edges::Bounded => pos + delta,
+            Edges::Wrapping => self.wrap(pos + delta),
+        };
+        self.get_v_ref(next).map(|tile| (next, tile))
+    }
+
+    /// The orthogonal neighbours of `pos` that are on the grid, in the order of [`Direction::all`]
+    pub fn neighbours4(&self, pos: IVec2, edges: Edges) -> impl Iterator<Item=(IVec2, &T)> + '_ {
+        Direction::all().into_iter()
+            .filter_map(move |direction| self.step(pos, direction.as_delta(), edges))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are on the grid, clockwise starting north
+    pub fn neighbours8(&self, pos: IVec2, edges: Edges) -> impl Iterator<Item=(IVec2, &T)> + '_ {
+        NEIGHBOURS_8.into_iter()
+            .filter_map(move |delta| self.step(pos, delta, edges))
+    }
+
+    /// The positions from `pos` (exclusive) in `direction` until the ray leaves the grid.
+    /// A wrapping ray stops just before it gets back to `pos`.
+    pub fn ray(&self, pos: IVec2, direction: Direction, edges: Edges) -> impl Iterator<Item=(IVec2, &T)> + '_ {
+        let delta = direction.as_delta();
+        let max_steps = match (edges, direction) {
+            (Edges::Bounded, _) => usize::MAX,
+            (Edges::Wrapping, Direction::North | Direction::South) => self.height.saturating_sub(1),
+            (Edges::Wrapping, Direction::East | Direction::West) => self.width.saturating_sub(1),
+        };
+
+        std::iter::successors(self.step(pos, delta, edges), move |&(cur, _)| self.step(cur, delta, edges))
+            .take(max_steps)
+    }
+
+    /// Borrows the `width` x `height` window with its top left corner at `(x, y)`, panics if it doesn't fit
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> View<'_, T> {
+        assert!(x + width <= self.width && y + height <= self.height, "view out of bounds");
+
+        View {
+            map: self,
+            x,
+            y,
             width,
             height,
         }
     }
+
+    pub fn find_all(&self, val: &T) -> Vec<(usize, usize)> where T: PartialEq {
+        self.iter()
+            .filter(|(_, tile)| *tile == val)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
 }
 
-impl<T> Map2D<T> where T: Copy {
+impl<T> Map2D<T> where T: Clone {
     pub fn from_size(width: usize, height: usize, val: T) -> Self {
         let buf = vec![val; width * height];
         Self {
@@ -63,38 +191,63 @@ impl<T> Map2D<T> where T: Copy {
         }
     }
 
-    pub fn is_in_bounds(&self, x: usize, y: usize) -> bool {
-        x < self.width && y < self.height
-    }
+    /// Builds a `width` x `height` map where each tile is cloned from the position `f` maps it to
+    fn remap(&self, width: usize, height: usize, f: impl Fn(usize, usize) -> (usize, usize)) -> Self {
+        let buf = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[f(x, y)].clone())
+            .collect();
 
-    pub fn get(&self, x: usize, y: usize) -> Option<T> {
-        match (x, y) {
-            (x, y) if self.is_in_bounds(x, y) => {
-                self.buf.get(x + y * self.width).copied()
-            }
-            _ => None
+        Self {
+            buf,
+            width,
+            height,
         }
     }
 
-    pub fn get_v(&self, pos: IVec2) -> Option<T> {
-        self.get(pos.x as usize, pos.y as usize)
+    /// Swaps rows and columns, mirroring the map along its main diagonal
+    pub fn transpose(&self) -> Self {
+        self.remap(self.height, self.width, |x, y| (y, x))
     }
 
-    pub fn set(&mut self, x: usize, y: usize, c: T) {
-        if !self.is_in_bounds(x, y) { panic!("out of bounds") }
-        self.buf[x + y * self.width] = c;
+    /// Rotates the map 90° clockwise, the west column becomes the top row
+    pub fn rotate_cw(&self) -> Self {
+        let height = self.height;
+        self.remap(self.height, self.width, |x, y| (y, height - 1 - x))
     }
 
-    pub fn set_v(&mut self, pos: IVec2, c: T) {
-        self.set(pos.x as usize, pos.y as usize, c);
+    /// Rotates the map 90° counter-clockwise (270° clockwise), the east column becomes the top row
+    pub fn rotate_ccw(&self) -> Self {
+        let width = self.width;
+        self.remap(self.height, self.width, |x, y| (width - 1 - y, x))
     }
 
-    pub fn iter_rows(&self) -> Chunks<'_, T> {
-        self.buf.chunks(self.width)
+    pub fn rotate_180(&self) -> Self {
+        let (width, height) = (self.width, self.height);
+        self.remap(width, height, |x, y| (width - 1 - x, height - 1 - y))
     }
 
-    pub fn iter_row(&self, row: usize) -> Iter<'_, T> {
-        self.buf[row * self.width..row * self.width + self.width].into_iter()
+    /// Mirrors the map left to right
+    pub fn flip_horizontal(&self) -> Self {
+        let width = self.width;
+        self.remap(self.width, self.height, |x, y| (width - 1 - x, y))
+    }
+
+    /// Mirrors the map top to bottom
+    pub fn flip_vertical(&self) -> Self {
+        let height = self.height;
+        self.remap(self.width, self.height, |x, y| (x, height - 1 - y))
+    }
+}
+
+impl<T> Map2D<T> where T: Copy {
+    pub fn get(&self, x: usize, y: usize) -> Option<T> {
+        self.get_ref(x, y).copied()
+    }
+
+    /// Returns `None` for positions off the grid, including negative ones
+    pub fn get_v(&self, pos: IVec2) -> Option<T> {
+        self.get_v_ref(pos).copied()
     }
 
     pub fn iter_cols(&'_ self) -> impl Iterator<Item=impl Iterator<Item=T> + '_> {
@@ -113,7 +266,59 @@ impl<T> Map2D<T> where T: Copy {
     }
 }
 
-impl<T> Debug for Map2D<T> where T: Display, T: Copy {
+/// A borrowed rectangular window into a [`Map2D`], positions are relative to its top left corner
+#[derive(Copy, Clone)]
+pub struct View<'a, T> {
+    map: &'a Map2D<T>,
+    x: usize,
+    y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl<'a, T> View<'a, T> {
+    pub fn get_ref(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+        self.map.get_ref(self.x + x, self.y + y)
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item=&'a [T]> + 'a {
+        let (map, x, width) = (self.map, self.x, self.width);
+        (self.y..self.y + self.height)
+            .map(move |y| &map.buf[x + y * map.width..x + y * map.width + width])
+    }
+
+    /// All tiles row by row, along with their position in the view
+    pub fn iter(&self) -> impl Iterator<Item=((usize, usize), &'a T)> + 'a {
+        self.iter_rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, tile)| ((x, y), tile)))
+    }
+
+    /// Copies the window out into its own map
+    pub fn to_map(&self) -> Map2D<T> where T: Clone {
+        Map2D {
+            buf: self.iter_rows().flatten().cloned().collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// Views are equal when they have the same size and tiles, wherever they are
+impl<T> PartialEq for View<'_, T> where T: PartialEq {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width
+            && self.height == other.height
+            && self.iter_rows().eq(other.iter_rows())
+    }
+}
+
+impl<T> Eq for View<'_, T> where T: Eq {}
+
+impl<T> Debug for View<'_, T> where T: Display {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         for row in self.iter_rows() {
             for c in row {
@@ -123,4 +328,153 @@ impl<T> Debug for Map2D<T> where T: Display, T: Copy {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Unchecked access by `(x, y)` for hot loops, panics out of bounds
+impl<T> Index<(usize, usize)> for Map2D<T> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        &self.buf[x + y * self.width]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Map2D<T> {
+    #[inline]
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        &mut self.buf[x + y * self.width]
+    }
+}
+
+impl<T> Debug for Map2D<T> where T: Display {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.iter_rows() {
+            for c in row {
+                write!(f, "{}", c)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "123\n456\n";
+
+    #[test]
+    fn test_parse() {
+        let map = Map2D::parse(INPUT, |c| c.to_digit(10)).unwrap();
+        assert_eq!((map.width, map.height), (3, 2));
+        assert_eq!(map.get(2, 1), Some(6));
+        assert_eq!(map[(1, 0)], 2);
+        assert_eq!(format!("{:?}", map), INPUT);
+
+        let err = Map2D::parse("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid tile \"x\" at line 2, column 2");
+    }
+
+    #[test]
+    fn test_signed_access() {
+        let mut map = Map2D::parse(INPUT, Some).unwrap();
+        assert_eq!(map.get_v(IVec2::new(0, 1)), Some('4'));
+        assert_eq!(map.get_v(IVec2::new(-1, 0)), None);
+        assert_eq!(map.get_v(IVec2::new(0, -1)), None);
+        assert_eq!(map.get_v(IVec2::new(3, 0)), None);
+        assert!(!map.contains(IVec2::new(-1, -1)));
+
+        map.set_v(IVec2::new(2, 0), 'x');
+        assert_eq!(map.get(2, 0), Some('x'));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_set_negative() {
+        Map2D::from_size(2, 2, 0).set_v(IVec2::new(-1, 0), 1);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let map = Map2D::from_vec((0..9).collect(), 3);
+        let values = |it: &mut dyn Iterator<Item=(IVec2, &i32)>| it.map(|(_, &v)| v).collect::<Vec<_>>();
+
+        assert_eq!(values(&mut map.neighbours4(IVec2::new(1, 1), Edges::Bounded)), [1, 7, 5, 3]);
+        assert_eq!(values(&mut map.neighbours4(IVec2::new(0, 0), Edges::Bounded)), [3, 1]);
+        assert_eq!(values(&mut map.neighbours4(IVec2::new(0, 0), Edges::Wrapping)), [6, 3, 1, 2]);
+
+        assert_eq!(values(&mut map.neighbours8(IVec2::new(1, 1), Edges::Bounded)), [1, 2, 5, 8, 7, 6, 3, 0]);
+        assert_eq!(values(&mut map.neighbours8(IVec2::new(2, 2), Edges::Bounded)), [5, 7, 4]);
+        assert_eq!(map.neighbours8(IVec2::new(2, 2), Edges::Wrapping).count(), 8);
+
+        let (pos, _) = map.neighbours8(IVec2::new(0, 0), Edges::Wrapping).last().unwrap();
+        assert_eq!(pos, IVec2::new(2, 2));
+    }
+
+    #[test]
+    fn test_ray() {
+        let map = Map2D::from_vec((0..12).collect(), 4);
+        let values = |it: &mut dyn Iterator<Item=(IVec2, &i32)>| it.map(|(_, &v)| v).collect::<Vec<_>>();
+
+        assert_eq!(values(&mut map.ray(IVec2::new(0, 1), Direction::East, Edges::Bounded)), [5, 6, 7]);
+        assert_eq!(values(&mut map.ray(IVec2::new(1, 2), Direction::North, Edges::Bounded)), [5, 1]);
+        assert_eq!(values(&mut map.ray(IVec2::new(3, 0), Direction::East, Edges::Bounded)), []);
+        assert_eq!(values(&mut map.ray(IVec2::new(-1, 0), Direction::East, Edges::Bounded)), [0, 1, 2, 3]);
+
+        assert_eq!(values(&mut map.ray(IVec2::new(2, 1), Direction::East, Edges::Wrapping)), [7, 4, 5]);
+        assert_eq!(values(&mut map.ray(IVec2::new(0, 0), Direction::North, Edges::Wrapping)), [8, 4]);
+    }
+
+    #[test]
+    fn test_transforms() {
+        let map = Map2D::parse(INPUT, Some).unwrap();
+        let show = |map: Map2D<char>| format!("{:?}", map);
+
+        assert_eq!(show(map.transpose()), "14\n25\n36\n");
+        assert_eq!(show(map.rotate_cw()), "41\n52\n63\n");
+        assert_eq!(show(map.rotate_ccw()), "36\n25\n14\n");
+        assert_eq!(show(map.rotate_180()), "654\n321\n");
+        assert_eq!(show(map.flip_horizontal()), "321\n654\n");
+        assert_eq!(show(map.flip_vertical()), "456\n123\n");
+
+        assert_eq!(map.rotate_cw().rotate_cw(), map.rotate_180());
+        assert_eq!(map.rotate_cw().rotate_ccw(), map);
+        assert_eq!(map.transpose().transpose(), map);
+    }
+
+    #[test]
+    fn test_view() {
+        let map = Map2D::parse("abab\ncdcd\nxyxy\n", Some).unwrap();
+        let left = map.view(0, 0, 2, 2);
+        let right = map.view(2, 0, 2, 2);
+
+        assert_eq!(format!("{:?}", left), "ab\ncd\n");
+        assert_eq!(left, right);
+        assert_ne!(left, map.view(1, 0, 2, 2));
+        assert_ne!(left, map.view(0, 0, 2, 3));
+
+        assert_eq!(right.get_ref(1, 1), Some(&'d'));
+        assert_eq!(right.get_ref(2, 0), None);
+        assert_eq!(right.iter().nth(2), Some(((0, 1), &'c')));
+        assert_eq!(map.view(1, 1, 3, 2).to_map(), Map2D::parse("dcd\nyxy", Some).unwrap());
+        assert_eq!(map.view(4, 3, 0, 0).iter().count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_out_of_bounds() {
+        Map2D::from_size(2, 2, 0).view(1, 0, 2, 1);
+    }
+
+    #[test]
+    fn test_iter() {
+        let map = Map2D::from_vec(vec![1, 2, 3, 4, 5, 6], 3);
+        assert_eq!(map.height, 2);
+        assert_eq!(map.iter_rows().collect::<Vec<_>>(), [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(map.iter_cols().map(|col| col.collect::<Vec<_>>()).collect::<Vec<_>>(), [[1, 4], [2, 5], [3, 6]]);
+        assert_eq!(map.iter().nth(4), Some(((1, 1), &5)));
+        assert_eq!(map.find_all(&3), [(2, 0)]);
+    }
+}
@@ -0,0 +1,123 @@
+use std::str::FromStr;
+use crate::error::ParseError;
+
+/// Parses `part`, a slice of `text`, as a number
+pub fn number<T: FromStr>(text: &str, part: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| ParseError::invalid("number", text, part))
+}
+
+/// Parses every whitespace separated number in `part`, a slice of `text`
+pub fn numbers<T: FromStr>(text: &str, part: &str) -> Result<Vec<T>, ParseError> {
+    part.split_whitespace()
+        .map(|n| number(text, n))
+        .collect()
+}
+
+/// Splits `part`, a slice of `text`, around the first `delimiter`
+pub fn split_once<'a>(text: &str, part: &'a str, delimiter: &str) -> Result<(&'a str, &'a str), ParseError> {
+    part.split_once(delimiter)
+        .ok_or_else(|| ParseError::expected(format!("{:?}", delimiter), text, part))
+}
+
+/// Strips `prefix` off `part`, a slice of `text`
+pub fn strip_prefix<'a>(text: &str, part: &'a str, prefix: &str) -> Result<&'a str, ParseError> {
+    part.strip_prefix(prefix).ok_or_else(|| {
+        // Point at as many characters as the prefix has, which needn't be as many bytes
+        let end = part.char_indices().nth(prefix.chars().count()).map_or(part.len(), |(idx, _)| idx);
+        ParseError::expected(format!("{:?}", prefix), text, &part[..end])
+    })
+}
+
+/// Parses each line of `input` with `f`, moving its errors to their position in `input`
+pub fn lines<'a, T>(input: &'a str, mut f: impl FnMut(&'a str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .map(|line| f(line).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+/// Parses a rectangular grid, mapping each character to a tile with `f`.
+/// Returns the tiles row by row, along with the width and height.
+pub fn grid<T>(input: &str, what: &'static str, mut f: impl FnMut(char) -> Option<T>) -> Result<(Vec<T>, usize, usize), ParseError> {
+    let mut buf = Vec::new();
+    let mut width = None;
+    let mut height = 0;
+
+    for line in input.lines() {
+        height += 1;
+        let mut row_width = 0;
+
+        for (idx, c) in line.char_indices() {
+            row_width += 1;
+            let tile = f(c).ok_or_else(|| ParseError::invalid(what, input, &line[idx..idx + c.len_utf8()]))?;
+            buf.push(tile);
+        }
+
+        match width {
+            None => width = Some(row_width),
+            Some(width) if width != row_width => {
+                return Err(ParseError::expected(format!("a row of {} tiles", width), input, line));
+            }
+            Some(_) => {}
+        }
+    }
+
+    Ok((buf, width.unwrap_or(0), height))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::error::Span;
+    use super::*;
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<u32>("12", "12"), Ok(12));
+
+        let text = "a 1x b";
+        let err = number::<u32>(text, &text[2..4]).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid number \"1x\" at line 1, column 3");
+    }
+
+    #[test]
+    fn test_lines() {
+        let input = "1 2\n3 x\n5";
+        assert_eq!(lines(input, |line| numbers::<u32>(line, line)).unwrap_err().span(), Span {
+            offset: 6,
+            len: 1,
+            line: 2,
+            col: 3,
+        });
+
+        let input = "1 2\n3\n";
+        assert_eq!(lines(input, |line| numbers::<u32>(line, line)), Ok(vec![vec![1, 2], vec![3]]));
+    }
+
+    #[test]
+    fn test_split_once() {
+        assert_eq!(split_once("a: b", "a: b", ": "), Ok(("a", "b")));
+        assert_eq!(split_once("a b", "a b", ": ").unwrap_err().to_string(), "Expected \": \" at line 1, column 1");
+    }
+
+    #[test]
+    fn test_strip_prefix() {
+        assert_eq!(strip_prefix("{a}", "{a}", "{"), Ok("a}"));
+
+        let err = strip_prefix("é}", "é}", "{").unwrap_err();
+        assert_eq!(err.to_string(), "Expected \"{\" at line 1, column 1");
+        assert_eq!(err.span().len, 2);
+        assert_eq!(strip_prefix("xé", "xé", "ab").unwrap_err().span().len, 3);
+    }
+
+    #[test]
+    fn test_grid() {
+        let (tiles, width, height) = grid("12\n34\n", "digit", |c| c.to_digit(10)).unwrap();
+        assert_eq!(tiles, [1, 2, 3, 4]);
+        assert_eq!((width, height), (2, 2));
+
+        let err = grid("12\n3x", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "Invalid digit \"x\" at line 2, column 2");
+
+        let err = grid("12\n345", "digit", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(err.to_string(), "Expected a row of 2 tiles at line 2, column 1");
+    }
+}
@@ -0,0 +1,310 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, VecDeque};
+use std::collections::hash_map::Entry;
+use std::hash::Hash;
+use std::ops::Add;
+use rustc_hash::FxHashMap;
+
+/// A path found by a search, from one of the start states up to and including the goal
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Every state seen so far gets an id, so the heap and parent links don't need to clone states
+struct Visited<S, C> {
+    ids: FxHashMap<S, usize>,
+    states: Vec<S>,
+    parents: Vec<Option<usize>>,
+    costs: Vec<C>,
+}
+
+impl<S, C> Visited<S, C> where S: Clone + Eq + Hash, C: Copy + Ord {
+    fn new() -> Self {
+        Self {
+            ids: FxHashMap::default(),
+            states: Vec::new(),
+            parents: Vec::new(),
+            costs: Vec::new(),
+        }
+    }
+
+    /// Records `state` if it's new or reached cheaper than before, returning its id if so
+    fn relax(&mut self, state: S, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.ids.entry(state) {
+            Entry::Occupied(entry) => {
+                let id = *entry.get();
+                if cost >= self.costs[id] {
+                    return None;
+                }
+                self.parents[id] = parent;
+                self.costs[id] = cost;
+                Some(id)
+            }
+            Entry::Vacant(entry) => {
+                let id = self.states.len();
+                self.states.push(entry.key().clone());
+                entry.insert(id);
+                self.parents.push(parent);
+                self.costs.push(cost);
+                Some(id)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![self.states[goal].clone()];
+        let mut cur = goal;
+        while let Some(parent) = self.parents[cur] {
+            states.push(self.states[parent].clone());
+            cur = parent;
+        }
+        states.reverse();
+
+        Path {
+            cost: self.costs[goal],
+            states,
+        }
+    }
+}
+
+/// Breadth first search for the path with the fewest steps to a state matching `is_goal`
+pub fn bfs<S, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item=S>
+{
+    let mut visited = Visited::new();
+    let mut queue = starts.into_iter()
+        .filter_map(|start| visited.relax(start, None, 0))
+        .collect::<VecDeque<_>>();
+
+    while let Some(id) = queue.pop_front() {
+        if is_goal(&visited.states[id]) {
+            return Some(visited.path(id));
+        }
+
+        let steps = visited.costs[id] + 1;
+        for next in successors(&visited.states[id]) {
+            if !visited.ids.contains_key(&next) {
+                queue.extend(visited.relax(next, Some(id), steps));
+            }
+        }
+    }
+
+    None
+}
+
+/// Breadth first search over everything reachable from `starts`, along with the fewest steps to get there
+pub fn bfs_distances<S, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+) -> FxHashMap<S, usize>
+    where S: Clone + Eq + Hash, I: IntoIterator<Item=S>
+{
+    let mut distances = FxHashMap::default();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = distances.entry(start.clone()) {
+            entry.insert(0);
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((state, distance)) = queue.pop_front() {
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Cheapest path to a state matching `is_goal`, `successors` yields the next states with the cost of moving there
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)>
+{
+    astar(starts, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate the remaining cost to a goal
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C>, I: IntoIterator<Item=(S, C)>
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(id) = visited.relax(start, None, C::default()) {
+            heap.push(Reverse((estimate, C::default(), id)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, id))) = heap.pop() {
+        // Already reached cheaper since this entry was pushed
+        if cost > visited.costs[id] {
+            continue;
+        }
+
+        if is_goal(&visited.states[id]) {
+            return Some(visited.path(id));
+        }
+
+        for (next, step_cost) in successors(&visited.states[id]) {
+            let next_cost = cost + step_cost;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_id) = visited.relax(next, Some(id), next_cost) {
+                heap.push(Reverse((estimate, next_cost, next_id)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Like [`dijkstra`], with a bucket queue indexed by cost instead of a binary heap.
+/// Pays off when step costs are small integers, as the queue only ever scans forward.
+pub fn dijkstra_buckets<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S: Clone + Eq + Hash, C: Copy + Ord + Default + Add<Output=C> + Into<u64>, I: IntoIterator<Item=(S, C)>
+{
+    let mut visited = Visited::new();
+    let mut buckets: Vec<Vec<usize>> = Vec::new();
+
+    let push = |buckets: &mut Vec<Vec<usize>>, cost: C, id: usize| {
+        let bucket = cost.into() as usize;
+        if bucket >= buckets.len() {
+            buckets.resize_with(bucket + 1, Vec::new);
+        }
+        buckets[bucket].push(id);
+    };
+
+    for start in starts {
+        if let Some(id) = visited.relax(start, None, C::default()) {
+            push(&mut buckets, C::default(), id);
+        }
+    }
+
+    let mut cur = 0;
+    while cur < buckets.len() {
+        let Some(id) = buckets[cur].pop() else {
+            cur += 1;
+            continue;
+        };
+
+        let cost = visited.costs[id];
+        // Already reached cheaper since this entry was pushed
+        if cost.into() as usize != cur {
+            continue;
+        }
+
+        if is_goal(&visited.states[id]) {
+            return Some(visited.path(id));
+        }
+
+        for (next, step_cost) in successors(&visited.states[id]) {
+            let next_cost = cost + step_cost;
+            if let Some(next_id) = visited.relax(next, Some(id), next_cost) {
+                push(&mut buckets, next_cost, next_id);
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Edges of a small weighted graph, the direct edge 0 -> 3 is dearer than going round
+    fn edges(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 4), (3, 10)],
+            1 => vec![(2, 1), (3, 7)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let path = bfs([0], |n| edges(n).into_iter().map(|(next, _)| next), |&n| n == 3).unwrap();
+        assert_eq!(path, Path { cost: 1, states: vec![0, 3] });
+
+        assert_eq!(bfs([0], |&n| [n + 1], |&n| n == 5).unwrap().states, [0, 1, 2, 3, 4, 5]);
+        assert_eq!(bfs([0], |n| edges(n).into_iter().map(|(next, _)| next), |&n| n == 4), None);
+        assert_eq!(bfs([3, 0], |_| [], |&n| n == 3).unwrap().states, [3]);
+    }
+
+    #[test]
+    fn test_bfs_distances() {
+        let distances = bfs_distances([0], |n| edges(n).into_iter().map(|(next, _)| next));
+        assert_eq!(distances.len(), 4);
+        assert_eq!(distances[&0], 0);
+        assert_eq!(distances[&2], 1);
+        assert_eq!(distances[&3], 1);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path, Path { cost: 4, states: vec![0, 1, 2, 3] });
+
+        assert_eq!(dijkstra([0], edges, |&n| n == 4), None);
+        assert_eq!(dijkstra([2, 0], edges, |&n| n == 3).unwrap().states, [2, 3]);
+    }
+
+    #[test]
+    fn test_dijkstra_buckets() {
+        let path = dijkstra_buckets([0], edges, |&n| n == 3).unwrap();
+        assert_eq!(path, Path { cost: 4, states: vec![0, 1, 2, 3] });
+
+        assert_eq!(dijkstra_buckets([0], edges, |&n| n == 4), None);
+
+        // Zero cost steps stay in the current bucket
+        let path = dijkstra_buckets([0u32], |&n| [(n + 1, u32::from(n % 2 == 0))], |&n| n == 6).unwrap();
+        assert_eq!(path.cost, 3);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Manhattan distance on an open grid, the heuristic is exact along the straight line
+        let goal = (4, 3);
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)].into_iter()
+                .filter(|&(x, y)| (0..8).contains(&x) && (0..8).contains(&y) && (x, y) != (2, 0))
+                .map(|next| (next, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| (goal.0 - x).abs() + (goal.1 - y).abs();
+
+        let path = astar([(0, 0)], successors, heuristic, |&pos| pos == goal).unwrap();
+        assert_eq!(path.cost, 7);
+        assert_eq!(path.states.len(), 8);
+        assert_eq!(path.states.first(), Some(&(0, 0)));
+        assert_eq!(path.states.last(), Some(&goal));
+        assert!(!path.states.contains(&(2, 0)));
+
+        assert_eq!(dijkstra([(0, 0)], successors, |&pos| pos == goal).unwrap().cost, 7);
+    }
+}